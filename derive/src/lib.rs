@@ -1,55 +1,184 @@
 //! Defines the [`Transient`][crate::Transient] derive macro that implements the
-//! [`Transient`][transient::tr::Transient] trait for a struct with at most 1
-//! lifetime parameter.
+//! [`Transient`][transient::tr::Transient] trait for a struct or enum with at
+//! most 8 lifetime parameters.
 use proc_macro::TokenStream;
 use proc_macro2::{Span, TokenStream as TokenStream2};
 use quote::{quote, ToTokens};
 use std::fmt;
+use syn::visit_mut::{self, VisitMut};
+use syn::BoundLifetimes;
+use syn::{
+    parse::{Parse, ParseStream},
+    punctuated::Punctuated,
+    Error as SynError, Result as SynResult, Token,
+};
 use syn::{
     parse_macro_input, parse_quote, spanned::Spanned, Attribute, Data, DeriveInput, Fields,
-    GenericParam, Generics, Ident, Lifetime, TypeGenerics, TypeParamBound, WhereClause,
+    GenericArgument, GenericParam, Generics, Ident, Lifetime, LitStr,
+    ParenthesizedGenericArguments, Path, TraitBoundModifier, Type, TypeGenerics, TypeParamBound,
+    WhereClause, WherePredicate,
 };
-use syn::{Error as SynError, Result as SynResult};
 
-/// Derive macro that implements the  [`Transient`] trait for a struct with
-/// at most 1 lifetime parameter.
+mod variance;
+
+/// Derive macro that implements the  [`Transient`] trait for a struct or enum
+/// with at most 8 lifetime parameters.
 ///
-/// This macro is limited to structs satisfying the following conditions:
-/// - There must be at most 1 lifetime parameter. Structs with extra lifetime
-///   parameters can easily implement the trait by hand, but care must be taken
-///   to ensure that the invariants detailed in the trait's [safety docs] are
-///   upheld.
+/// This macro is limited to structs and enums satisfying the following conditions:
+/// - There must be at most 8 lifetime parameters. Types with more than one
+///   lifetime are given a tuple `Transience` with one element per lifetime,
+///   in the order they are declared (e.g. `(Inv<'a>, Inv<'b>)`). Since the
+///   `transient` crate implements `Transience` for tuples of up to 8 elements,
+///   this limit also counts the elements added for each type parameter marked
+///   with `#[transient(bound)]` and for the reference impls described below.
 /// - There may be any number of type (or const) parameters, but the trait
-///   will only be implemented where `T: 'static` for each type parameter `T`.
+///   will only be implemented where `T: 'static` for each type parameter `T`
+///   unless it is marked with `#[transient(bound)]` (described below).
+/// - Any bounds on the generic parameters (including outlives bounds such as
+///   `'b: 'a`) and the `where` clause are carried over to the generated impl.
+///   Since these must also hold for the `Static` type, a trait bound that uses
+///   one of the type's lifetimes (such as `T: Trait<'a>`) is generalized over
+///   it in the impl (`for<'x> T: Trait<'x>`), and a bound on a type parameter
+///   marked with `#[transient(bound)]` is repeated for its `Static` type. A
+///   lifetime that is only used in the output of a bound (such as
+///   `F: Fn(&str) -> &'a str`) can't be generalized, so the impl instead
+///   requires `'a: 'static` (which a `'static` type parameter satisfying the
+///   bound requires anyway).
+///
+/// # Variance inference
+/// By default, the [variance] of a deriving type with respect to each of its
+/// lifetime parameters is inferred from the types of its fields, following the
+/// same rules as the compiler:
+/// - `&'a T` is _covariant_ in `'a` and in `T`, while `&'a mut T` is covariant
+///   in `'a` but _invariant_ in `T`.
+/// - Arguments of a `fn` pointer are _contravariant_, and its output is covariant.
+/// - Tuples, arrays, and slices are covariant in their elements, as are the
+///   `Box`, `Vec`, `Option`, `Result`, `Rc`, and `Arc` containers from `std`.
+/// - `PhantomData<X>` is treated exactly as if the field were `X`, so that
+///   zero-sized markers such as `PhantomData<&'a mut ()>` follow the usual rules.
+/// - The `Co`, `Contra`, and `Inv` markers from this crate declare their variance.
+/// - A trait object `dyn Trait + 'a` is covariant in `'a`, but invariant in any
+///   lifetimes used by the trait itself.
+/// - The interior mutability containers `Cell`, `RefCell`, `Mutex`, and `RwLock`
+///   are _invariant_ in their parameter, so `Cell<&'a T>` is invariant in `'a`.
+/// - Raw pointers and `UnsafeCell` can hide borrows whose variance cannot be
+///   determined from the type alone, so these produce an error unless the
+///   variance is declared explicitly using the attribute described below.
+/// - Any other type using a lifetime (such as a user-defined `Custom<'a>`, or a
+///   container named by a path into a module other than `std`, `core`, or
+///   `alloc`) is conservatively assumed to be invariant in it, as is `Self`.
+///
+/// When a lifetime is used in multiple positions that disagree, the result
+/// collapses to _invariant_, and a lifetime that cannot be found in any field
+/// is also assumed to be invariant.
+///
+/// Since the containers, `PhantomData`, and the markers are recognized by name,
+/// a lifetime inferred to be covariant (or contravariant) is also checked by the
+/// compiler using a coercion to a shorter (or longer) lifetime, which fails with
+/// a "lifetime may not live long enough" error pointing at the derive if the
+/// type only looks covariant (such as when a local alias named `Option` is used
+/// for an invariant type). Declaring the variance explicitly skips this check.
+///
+/// Every lifetime used in a field must be either `'static`, a lifetime parameter
+/// of the type, or bound within the field's type itself (such as the lifetimes
+/// of a `fn` pointer or a `for<'x>` binder), and any other lifetime produces an
+/// error. An elided lifetime (`&T` or `'_`) outside of a `fn` pointer is taken
+/// to be the type's lifetime parameter if it has exactly one, and is rejected
+/// otherwise.
 ///
 /// # Customization
-/// By default, the [variance] of a deriving struct is assumed to be _invariant_
-/// with respect to its lifetime parameter (if it has one), since this is the
-/// only type of variance that can be safely used for _all_ types without
-/// analyzing the behavior of its fields (which this macro does not attempt to
-/// do). When the added flexibility of _covariance_ or _contravariance_ is
-/// needed, the "variance(...)" helper attribute can be used to `unsafe`-ly
-/// override this default if you are confident that the chosen variance is
-/// appropriate for the type; however, you should first review the [safety docs]
-/// for the `Transient` trait (particularly related to its `Transience` associated
-/// type to ensure that its invariants are upheld.
+/// When the inferred variance is too conservative (such as for a field of a
+/// custom type that is known to be covariant), the "variance(...)" helper
+/// attribute can be used to `unsafe`-ly override the inference if you are
+/// confident that the chosen variance is appropriate for the type; however, you
+/// should first review the [safety docs] for the `Transient` trait (particularly
+/// related to its `Transience` associated type to ensure that its invariants are
+/// upheld.
 ///
 /// To set the variance for your type, annotate one of its fields (preferably
 /// either the _first_ field or the field with the lifetime, but any will do)
 /// with the `#[variance(...)]` attribute, substituting the ellipsis for one
-/// of the following keywords:
+/// of the following keywords. The chosen variance is applied to every lifetime
+/// parameter of the type. For an enum, the fields of every variant are
+/// searched and the attribute applies to the enum as a whole:
 ///
 /// |  Keyword | Alias | Description |
 /// | :-  | :- | :- |
-/// | `invariant` | `inv` | Declares a _invariant_ relationship with the lifetime; this is the default for types with a lifetime parameter.
+/// | `invariant` | `inv` | Declares a _invariant_ relationship with the lifetime; this is always safe.
 /// | `unsafe_covariant` | `unsafe_co` | Declares a _covariant_ relationship with the lifetime; this is `unsafe`.
-/// | `unsafe_contravariant` | `unsafe_contra` | Declares a _covariant_ relationship with the lifetime; this is `unsafe`.
+/// | `unsafe_contravariant` | `unsafe_contra` | Declares a _contravariant_ relationship with the lifetime; this is `unsafe`.
+///
+/// For types that mix variances across their lifetimes, the variance of each
+/// lifetime can instead be declared separately using the
+/// `#[variance('a = <keyword>, 'b = <keyword>)]` form, with the declarations
+/// spread across as many fields (or attributes) as is convenient. Once any
+/// variance is declared the inference is skipped entirely, so every lifetime
+/// that isn't mentioned is treated as _invariant_.
+///
+/// A declared variance is still checked against the uses of the lifetime whose
+/// variance is certain, such as `&'a mut &'a T`, `fn(&'a str)`, or
+/// `Cell<&'a T>`, and contradicting one of these produces an error pointing at
+/// the attribute. Uses inside other generic types (such as `Wrapper<'a>`) are
+/// not checked, since overriding the inference for these is the purpose of the
+/// attribute. In the rare case that a contradicting declaration is intended,
+/// the check can be skipped by adding `unchecked` to one of the attributes
+/// (e.g. `#[variance(unsafe_co, unchecked)]`).
 ///
 /// This can fail for any of the following reasons:
+/// - Omitting the attribute for a type with a raw pointer or `UnsafeCell` field
 /// - Requesting any variance for a type with no lifetime parameters
 /// - Requesting co- or contra-variance without the 'unsafe_' prefix
+/// - Declaring the variance of a lifetime that the type does not have
+/// - Declaring a variance that contradicts the fields without `unchecked`
+/// - Omitting the variance of a lifetime for a type marked `#[transient(strict)]`
 /// - Providing more than one "variance" attribute with conflicting values
+///   (including on the fields of different enum variants, or for a lifetime
+///   that is also covered by the `#[variance(...)]` form)
+///
+/// # Strict mode
+/// For code that should never rely on the inference (such as for auditing a
+/// crate with many `unsafe` declarations), the `#[transient(strict)]` attribute
+/// can be placed on the type to disable it. The variance of every lifetime must
+/// then be declared using the `variance` attribute (with `#[variance(inv)]`
+/// for an invariant lifetime), and omitting it for any lifetime is an error.
 ///
+/// # Transient type parameters
+/// A type parameter can be marked with the `#[transient(bound)]` attribute to
+/// allow it to be substituted with a non-`'static` type. The generated impl
+/// then requires `T: Transient` instead of `T: 'static`, uses `T::Static` in
+/// place of `T` in the `Static` type, and appends `T::Transience` to the
+/// `Transience` after the elements for each lifetime (in declaration order).
+///
+/// Since `T::Transience` is included unchanged, a marked parameter may only be
+/// used in _covariant_ positions (such as `T`, `&'a T`, `[T; N]`, or `Vec<T>`),
+/// and using it anywhere else (such as in `&'a mut T`, `fn(T)`, or `Cell<T>`)
+/// produces an error. As with an inferred variance, this is also checked by the
+/// compiler by coercing the type with `T` replaced by a reference to a shorter
+/// lifetime, so that a type which only looks covariant in `T` is rejected.
+///
+/// For a type that also borrows for the lifetime that `T` is expected to carry,
+/// such as `struct Cache<'a, T> { items: Vec<T>, name: &'a str }`, the parameter
+/// can instead be marked with `#[transient(bound = 'a)]`. Rather than being
+/// appended, `T::Transience` is then combined with the variance of `'a` using
+/// the [`Meet`] trait, so `Cache<'a, &'a str>` is covariant in `'a` while
+/// `Cache<'a, T>` for a `T` with an `Inv<'a>` transience is invariant in it.
+/// This requires `T::Transience` to be `Timeless` or a single marker for `'a`,
+/// which is enforced by a `Meet` bound in the generated impl.
+///
+/// # Reference impls
+/// The `#[transient(references)]` attribute can be placed on the type to also
+/// generate impls for `&'r S` and `&'r mut S`, whose `Transience` is that of
+/// the type with a covariant `Co<'r>` prepended. Behind the mutable reference
+/// every lifetime of the type is invariant, so `&'r mut S<'a>` is given the
+/// `(Co<'r>, Inv<'a>)` transience even if `S` is covariant in `'a`. This option
+/// cannot be combined with `#[transient(bound)]` (or `bound = 'a`) type
+/// parameters.
+///
+/// # Crate path
+/// The generated impl refers to the `transient` crate as `::transient` by default.
+/// If the crate has been renamed or re-exported under a different path, the
+/// `#[transient(crate = path::to::transient)]` attribute can be placed on the
+/// type to override it (the path may also be given as a string literal).
 ///
 /// # Examples
 /// Invocation with a type parameter and a lifetime parameter:
@@ -61,39 +190,131 @@ use syn::{Error as SynError, Result as SynResult};
 ///     value: &'a T,
 /// }
 /// ```
-/// This will generate the following impl:
+/// This will generate the following impl, with the variance inferred from `&'a T`:
 /// ```
 /// # struct S<'a, T> {value: &'a T}
 /// unsafe impl<'a, T: 'static> transient::Transient for S<'a, T> {
 ///     type Static = S<'static, T>;
-///     type Transience = transient::Inv<'a>;
+///     type Transience = transient::Co<'a>;
 /// }
 /// ```
 ///
-/// Invocation with a single lifetime and an attribute declaring _covariance_:
+/// Invocation with a single lifetime and an attribute declaring _covariance_
+/// (which would otherwise be inferred as _invariant_ due to the custom type):
 /// ```no_run
 /// use transient::Transient;
 ///
+/// #[derive(Debug, Clone, PartialEq, Eq)]
+/// struct Values<'a>(&'a [i32]);
+///
 /// #[derive(Debug, Clone, PartialEq, Eq, Transient)]
 /// struct S<'a> {
-///     #[variance(unsafe_co)]
 ///     name: String,
-///     values: &'a [i32]
+///     #[variance(unsafe_co)]
+///     values: Values<'a>,
 /// }
 /// ```
 /// The generated impl will then be:
 /// ```
-/// # struct S<'a> {name: String, values: &'a [i32]}
+/// # struct Values<'a>(&'a [i32]);
+/// # struct S<'a> {name: String, values: Values<'a>}
 /// unsafe impl<'a> transient::Transient for S<'a> {
 ///     type Static = S<'static>;
 ///     type Transience = transient::Co<'a>;
 /// }
 /// ```
 ///
+/// Invocation with multiple lifetimes and an attribute declaring _contravariance_:
+/// ```no_run
+/// use transient::Transient;
+///
+/// #[derive(Debug, Clone, PartialEq, Eq, Transient)]
+/// struct S<'a, 'b> {
+///     #[variance(unsafe_contra)]
+///     func_a: fn(&'a str),
+///     func_b: fn(&'b str),
+/// }
+/// ```
+/// The generated impl will then be:
+/// ```
+/// # struct S<'a, 'b> {func_a: fn(&'a str), func_b: fn(&'b str)}
+/// unsafe impl<'a, 'b> transient::Transient for S<'a, 'b> {
+///     type Static = S<'static, 'static>;
+///     type Transience = (transient::Contra<'a>, transient::Contra<'b>);
+/// }
+/// ```
+///
+/// Invocation with the variance declared separately for each lifetime:
+/// ```no_run
+/// use transient::Transient;
+///
+/// # #[derive(Debug, Clone, PartialEq, Eq)]
+/// # struct Values<'a>(&'a [i32]);
+/// #[derive(Debug, Clone, PartialEq, Eq, Transient)]
+/// struct S<'a, 'b, 'c> {
+///     #[variance('a = unsafe_co)]
+///     values: Values<'a>,
+///     #[variance('b = unsafe_contra)]
+///     func: fn(&'b str),
+///     other: Values<'c>,
+/// }
+/// ```
+/// The generated impl will then be:
+/// ```
+/// # struct Values<'a>(&'a [i32]);
+/// # struct S<'a, 'b, 'c> {values: Values<'a>, func: fn(&'b str), other: Values<'c>}
+/// unsafe impl<'a, 'b, 'c> transient::Transient for S<'a, 'b, 'c> {
+///     type Static = S<'static, 'static, 'static>;
+///     type Transience = (
+///         transient::Co<'a>, transient::Contra<'b>, transient::Inv<'c>
+///     );
+/// }
+/// ```
+///
+/// Invocation with a type parameter marked with `#[transient(bound)]`:
+/// ```no_run
+/// use transient::Transient;
+///
+/// #[derive(Debug, Clone, PartialEq, Eq, Transient)]
+/// struct S<'a, #[transient(bound)] T> {
+///     name: &'a str,
+///     values: Vec<T>,
+/// }
+/// ```
+/// The generated impl will then be:
+/// ```
+/// # struct S<'a, T> {name: &'a str, values: Vec<T>}
+/// unsafe impl<'a, T: transient::Transient> transient::Transient for S<'a, T> {
+///     type Static = S<'static, T::Static>;
+///     type Transience = (transient::Co<'a>, T::Transience);
+/// }
+/// ```
+///
+/// Enums are supported in the same way, with the attribute placed on a field
+/// of any variant:
+/// ```no_run
+/// use transient::Transient;
+///
+/// #[derive(Debug, Clone, PartialEq, Eq, Transient)]
+/// enum Node<'a> {
+///     Leaf(#[variance(unsafe_co)] &'a str),
+///     Branch(&'a Node<'a>),
+/// }
+/// ```
+/// The generated impl will then be:
+/// ```
+/// # enum Node<'a> {Leaf(&'a str), Branch(&'a Node<'a>)}
+/// unsafe impl<'a> transient::Transient for Node<'a> {
+///     type Static = Node<'static>;
+///     type Transience = transient::Co<'a>;
+/// }
+/// ```
+///
+/// [`Meet`]: ../transient/trait.Meet.html
 /// [`Transient`]: ../transient/trait.Transient.html
 /// [safety docs]: ../transient/trait.Transient.html#Safety
 /// [variance]: https://doc.rust-lang.org/nomicon/subtyping.html
-#[proc_macro_derive(Transient, attributes(variance))]
+#[proc_macro_derive(Transient, attributes(transient, variance))]
 pub fn derive_transient(input: TokenStream) -> TokenStream {
     let input: DeriveInput = parse_macro_input!(input as DeriveInput);
     let tokens = generate_impl(input).unwrap_or_else(|e| e.into_compile_error());
@@ -104,24 +325,111 @@ fn generate_impl(input: DeriveInput) -> SynResult<TokenStream2> {
     let span = input.span();
     let name = &input.ident;
 
-    let params = process_generics(input.generics)?;
-    let variance = parse_struct(&input.data, params.is_static(), span)?;
+    let options = Options::from_attrs(&input.attrs)?;
+    let mut params = process_generics(input.generics, &options.krate)?;
+    let (variances, inferred) = parse_data(&input.data, &params.lifetimes, options.strict, span)?;
+    variance::check(&input.data, &params.lifetimes, &params.bound_params)?;
+    let mut assertions = match inferred {
+        true => params.variance_assertions(name, &variances)?,
+        false => quote!(),
+    };
+    assertions.extend(params.param_assertions(name)?);
+    params.add_meet_predicates(&variances, &options.krate);
 
     let impl_generics = params.impl_generics();
     let (ty_generics, where_clause) = params.split_for_impl();
     let static_ty_generics = params.static_type_generics();
-    let transience_generics = params.transience_generics();
+    let krate = &options.krate;
+    let transience = params.transience(&variances, krate);
 
-    let tokens = quote!(
-        unsafe impl #impl_generics ::transient::Transient for #name #ty_generics
+    let mut tokens = quote!(
+        #[automatically_derived]
+        unsafe impl #impl_generics #krate::Transient for #name #ty_generics
         #where_clause {
             type Static = #name #static_ty_generics;
-            type Transience = #variance #transience_generics;
+            type Transience = #transience;
         }
+        #assertions
     );
+    if let Some(span) = options.references {
+        if !params.bound_params.is_empty() {
+            return Err(Error::ReferencesWithBoundParams(span).into());
+        }
+        if params.lifetimes.len() == MAX_ELEMENTS {
+            return Err(Error::TooManyLifetimes(span).into());
+        }
+        let lifetime: Lifetime = parse_quote!('__transient_ref);
+        let mut ref_generics = impl_generics.clone();
+        ref_generics.params.insert(0, parse_quote!(#lifetime));
+        let (ref_impl_generics, _, _) = ref_generics.split_for_impl();
+        let shared = params.reference_transience(&lifetime, &variances, false, krate);
+        let mutable = params.reference_transience(&lifetime, &variances, true, krate);
+        tokens.extend(quote!(
+            #[automatically_derived]
+            unsafe impl #ref_impl_generics #krate::Transient for &#lifetime #name #ty_generics
+            #where_clause {
+                type Static = &'static #name #static_ty_generics;
+                type Transience = #shared;
+            }
+            #[automatically_derived]
+            unsafe impl #ref_impl_generics #krate::Transient for &#lifetime mut #name #ty_generics
+            #where_clause {
+                type Static = &'static mut #name #static_ty_generics;
+                type Transience = #mutable;
+            }
+        ));
+    }
     Ok(tokens)
 }
 
+/// Options set by the `#[transient(...)]` container attribute
+struct Options {
+    /// Path to the `transient` crate, set by `#[transient(crate = path)]`
+    krate: Path,
+    /// Span of the `#[transient(references)]` option, if it was given
+    references: Option<Span>,
+    /// Whether the variance must be declared for every lifetime, set by
+    /// `#[transient(strict)]`
+    strict: bool,
+}
+
+impl Options {
+    fn from_attrs(attrs: &[Attribute]) -> Result<Self> {
+        let mut options = Options {
+            krate: parse_quote!(::transient),
+            references: None,
+            strict: false,
+        };
+        for attr in attrs.iter() {
+            if attr.path().is_ident("transient") {
+                attr.parse_nested_meta(|meta| {
+                    if meta.path.is_ident("crate") {
+                        let value = meta.value()?;
+                        options.krate = match value.peek(LitStr) {
+                            true => value.parse::<LitStr>()?.parse()?,
+                            false => value.parse()?,
+                        };
+                        Ok(())
+                    } else if meta.path.is_ident("references") {
+                        options.references = Some(meta.path.span());
+                        Ok(())
+                    } else if meta.path.is_ident("strict") {
+                        options.strict = true;
+                        Ok(())
+                    } else {
+                        Err(Error::UnexpectedOption(meta.path.span()).into())
+                    }
+                })?;
+            }
+        }
+        Ok(options)
+    }
+}
+
+/// The longest `Transience` tuple implemented by the `transient` crate, which
+/// limits the number of lifetimes and (unmet) bound type parameters in total
+const MAX_ELEMENTS: usize = 8;
+
 const VALID_VARIANCES: [&str; 6] = [
     "inv",
     "invariant",
@@ -131,7 +439,7 @@ const VALID_VARIANCES: [&str; 6] = [
     "unsafe_contravariant",
 ];
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 enum VarianceKind {
     Covariant,
     Contravariant,
@@ -146,6 +454,25 @@ impl VarianceKind {
     fn unspanned(self) -> Variance {
         Variance(self, None)
     }
+    /// Combine two uses of the same lifetime; if they disagree then the
+    /// lifetime can only be invariant.
+    fn meet(self, other: Self) -> Self {
+        match self == other {
+            true => self,
+            false => VarianceKind::Invariant,
+        }
+    }
+    /// Get the variance of a use nested inside a position with this variance,
+    /// such as the argument of a `fn` (contravariant) inside a `&'a` (covariant).
+    fn compose(self, inner: Self) -> Self {
+        use VarianceKind::*;
+        match (self, inner) {
+            (Covariant, inner) => inner,
+            (Contravariant, Covariant) => Contravariant,
+            (Contravariant, Contravariant) => Covariant,
+            _ => Invariant,
+        }
+    }
 }
 
 #[derive(Clone, Debug)]
@@ -178,10 +505,10 @@ impl Variance {
 impl ToTokens for Variance {
     fn to_tokens(&self, stream: &mut TokenStream2) {
         let tokens = match &self.0 {
-            VarianceKind::Static => quote!(::transient::Timeless),
-            VarianceKind::Invariant => quote!(::transient::Inv),
-            VarianceKind::Covariant => quote!(::transient::Co),
-            VarianceKind::Contravariant => quote!(::transient::Contra),
+            VarianceKind::Static => quote!(Timeless),
+            VarianceKind::Invariant => quote!(Inv),
+            VarianceKind::Covariant => quote!(Co),
+            VarianceKind::Contravariant => quote!(Contra),
         };
         tokens.to_tokens(stream);
     }
@@ -199,52 +526,167 @@ impl fmt::Display for Variance {
     }
 }
 
-fn parse_struct(data: &Data, is_static: bool, span: Span) -> Result<Variance> {
-    let Data::Struct(data) = data else {
-        return Err(Error::NotAStruct(span));
-    };
-    let fields = match &data.fields {
-        Fields::Named(fields) => &fields.named,
-        Fields::Unnamed(fields) => &fields.unnamed,
-        Fields::Unit => return Ok(VarianceKind::Static.unspanned()),
+/// Determine the variance with respect to each lifetime, using the `variance`
+/// attributes if any were provided and inferring it from the fields otherwise,
+/// along with whether it was inferred. In `strict` mode the variance is never
+/// inferred, and must be declared for every lifetime.
+fn parse_data(
+    data: &Data,
+    lifetimes: &[Lifetime],
+    strict: bool,
+    span: Span,
+) -> Result<(Vec<Variance>, bool)> {
+    let mut declared = Declared::default();
+    match data {
+        Data::Struct(data) => search_fields(&data.fields, &mut declared)?,
+        Data::Enum(data) => {
+            for variant in data.variants.iter() {
+                search_fields(&variant.fields, &mut declared)?;
+            }
+        }
+        Data::Union(_) => return Err(Error::NotAStructOrEnum(span)),
     };
-    let mut variance: Option<Variance> = None;
-    for field in fields.iter() {
-        search_for_variance(&field.attrs, &mut variance)?;
+    match declared {
+        Declared {
+            all: None, each, ..
+        } if each.is_empty() && !strict => {
+            let variances = variance::infer(data, lifetimes)?
+                .into_iter()
+                .map(VarianceKind::unspanned)
+                .collect();
+            Ok((variances, true))
+        }
+        Declared { all: Some(all), .. } if lifetimes.is_empty() => {
+            Err(Error::StaticTypeWithVariance(all.span()))
+        }
+        Declared {
+            all,
+            each,
+            unchecked,
+        } => {
+            if let Some((lifetime, _)) = each.iter().find(|(lt, _)| !lifetimes.contains(lt)) {
+                let string = lifetime.to_string();
+                let span = lifetime.span();
+                return Err(Error::UnknownLifetime { string, span });
+            }
+            let variances = lifetimes
+                .iter()
+                .map(|lifetime| {
+                    let mut variance = all.clone();
+                    for (_, new) in each.iter().filter(|(lt, _)| lt == lifetime) {
+                        merge_variance(&mut variance, new.clone())?;
+                    }
+                    match (variance, strict) {
+                        (Some(variance), _) => Ok(variance),
+                        (None, false) => Ok(VarianceKind::Invariant.unspanned()),
+                        (None, true) => {
+                            let string = lifetime.to_string();
+                            let span = lifetime.span();
+                            Err(Error::MissingVariance { string, span })
+                        }
+                    }
+                })
+                .collect::<Result<Vec<_>>>()?;
+            if !unchecked {
+                variance::verify(data, lifetimes, &variances)?;
+            }
+            Ok((variances, false))
+        }
+    }
+}
+
+/// The variances declared by the `variance` attributes on the fields
+#[derive(Default)]
+struct Declared {
+    /// set by `#[variance(...)]`, applying to every lifetime
+    all: Option<Variance>,
+    /// set by `#[variance('a = ...)]`, applying to a single lifetime
+    each: Vec<(Lifetime, Variance)>,
+    /// set by `#[variance(unchecked)]`, skipping the check against the fields
+    unchecked: bool,
+}
+
+/// A single argument to the `variance` attribute
+enum VarianceArg {
+    /// `#[variance(unsafe_co)]`
+    All(Ident),
+    /// `#[variance('a = unsafe_co)]`
+    Lifetime(Lifetime, Ident),
+    /// `#[variance(unsafe_co, unchecked)]`
+    Unchecked,
+}
+
+impl Parse for VarianceArg {
+    fn parse(input: ParseStream<'_>) -> SynResult<Self> {
+        if input.peek(Lifetime) {
+            let lifetime = input.parse()?;
+            input.parse::<Token![=]>()?;
+            return Ok(VarianceArg::Lifetime(
+                lifetime,
+                parse_variance_ident(input)?,
+            ));
+        }
+        let ident = parse_variance_ident(input)?;
+        match ident == "unchecked" {
+            true => Ok(VarianceArg::Unchecked),
+            false => Ok(VarianceArg::All(ident)),
+        }
     }
-    match variance {
-        Some(variance) if !is_static => Ok(variance),
-        Some(variance) => Err(Error::StaticTypeWithVariance(variance.span())),
-        None if is_static => Ok(VarianceKind::Static.unspanned()),
-        None => Ok(VarianceKind::Invariant.unspanned()),
+}
+
+fn parse_variance_ident(input: ParseStream<'_>) -> SynResult<Ident> {
+    let path = input.call(Path::parse_mod_style)?;
+    match path.get_ident() {
+        Some(ident) => Ok(ident.clone()),
+        None => Err(Error::ExpectedIdent(path.span()).into()),
     }
 }
 
-fn search_for_variance(attrs: &[Attribute], variance: &mut Option<Variance>) -> Result<()> {
+fn search_fields(fields: &Fields, declared: &mut Declared) -> Result<()> {
+    for field in fields.iter() {
+        search_for_variance(&field.attrs, declared)?;
+    }
+    Ok(())
+}
+
+fn search_for_variance(attrs: &[Attribute], declared: &mut Declared) -> Result<()> {
     for attr in attrs.iter() {
         if attr.path().is_ident("variance") {
-            attr.parse_nested_meta(|meta| {
-                let ident = meta
-                    .path
-                    .get_ident()
-                    .ok_or_else(|| Error::ExpectedIdent(meta.path.span()))?;
-                match variance.replace(Variance::from_ident(ident)?) {
-                    Some(old) => {
-                        let new = variance.as_ref().unwrap().clone();
-                        Err(Error::DuplicateVariance { old, new }.into())
+            let args =
+                attr.parse_args_with(Punctuated::<VarianceArg, Token![,]>::parse_terminated)?;
+            for arg in args {
+                match arg {
+                    VarianceArg::All(ident) => {
+                        merge_variance(&mut declared.all, Variance::from_ident(&ident)?)?
+                    }
+                    VarianceArg::Lifetime(lifetime, ident) => {
+                        let new = Variance::from_ident(&ident)?;
+                        declared.each.push((lifetime, new));
                     }
-                    None => Ok(()),
+                    VarianceArg::Unchecked => declared.unchecked = true,
                 }
-            })?;
+            }
         }
     }
     Ok(())
 }
 
-fn static_type_bound() -> TypeParamBound {
-    parse_quote! { 'static }
+/// Record a declared variance, failing if it conflicts with a previous one.
+fn merge_variance(variance: &mut Option<Variance>, new: Variance) -> Result<()> {
+    match variance.as_ref() {
+        Some(old) if old.0 != new.0 => {
+            let old = old.clone();
+            Err(Error::DuplicateVariance { old, new })
+        }
+        Some(_) => Ok(()),
+        None => {
+            *variance = Some(new);
+            Ok(())
+        }
+    }
 }
-fn static_param() -> GenericParam {
+
+fn static_type_bound() -> TypeParamBound {
     parse_quote! { 'static }
 }
 fn no_generics() -> Generics {
@@ -257,103 +699,850 @@ struct Params {
     impl_: Generics,    // <---'                             |        |
     original: Generics, // <---------------------------------'--------'
     //                type Static = Struct<'static, T>;
-    static_: Generics, // <-----------------------'
+    static_: Vec<TokenStream2>, // <-------------'
     //                type Transience = Co<'src>;
-    lifetime: Option<Lifetime>, // <--------'
+    lifetimes: Vec<Lifetime>, // <----------'
+    /// type parameters marked with `#[transient(bound)]`, whose `Transience`
+    /// is appended to that of the lifetimes
+    bound_params: Vec<Ident>,
+    /// the subset of `bound_params` marked with `#[transient(bound = 'a)]`,
+    /// whose `Transience` is instead met with the element for `'a`
+    met_params: Vec<(Ident, Lifetime)>,
 }
 impl Params {
-    fn new(
-        lifetime: Option<Lifetime>,
-        original: Generics,
-        impl_: Generics,
-        static_: Vec<GenericParam>,
-    ) -> Self {
+    fn empty() -> Self {
         Params {
-            lifetime,
-            original,
-            impl_,
-            static_: parse_quote!(<#(#static_,)*>),
+            impl_: no_generics(),
+            original: no_generics(),
+            static_: vec![],
+            lifetimes: vec![],
+            bound_params: vec![],
+            met_params: vec![],
         }
     }
 
-    fn empty() -> Self {
-        Params::new(None, no_generics(), no_generics(), vec![])
+    /// Assemble the `Transience` type from the variance for each lifetime and
+    /// the transience of each bound type parameter, using a tuple with one
+    /// element per lifetime (in declaration order) followed by one element per
+    /// bound type parameter when there is more than one.
+    fn transience(&self, variances: &[Variance], krate: &Path) -> TokenStream2 {
+        let lifetimes = self.lifetimes.iter();
+        let elements: Vec<TokenStream2> = variances
+            .iter()
+            .zip(lifetimes)
+            .map(|(variance, lifetime)| self.met_element(variance, lifetime, krate).0)
+            .chain(
+                self.bound_params
+                    .iter()
+                    .filter(|param| !self.met_params.iter().any(|(met, _)| met == *param))
+                    .map(|param| quote!(<#param as #krate::Transient>::Transience)),
+            )
+            .collect();
+        collapse_transience(&elements, krate)
+    }
+
+    /// Assemble the element of the `Transience` for a lifetime by meeting its
+    /// variance with the `Transience` of each type parameter bound to it, along
+    /// with the `Meet` predicates required for each step to be well-formed.
+    fn met_element(
+        &self,
+        variance: &Variance,
+        lifetime: &Lifetime,
+        krate: &Path,
+    ) -> (TokenStream2, Vec<WherePredicate>) {
+        let mut element = quote!(#krate::#variance<#lifetime>);
+        let mut predicates = vec![];
+        for (param, _) in self.met_params.iter().filter(|(_, lt)| lt == lifetime) {
+            let other = quote!(<#param as #krate::Transient>::Transience);
+            predicates.push(parse_quote!(#element: #krate::Meet<#other>));
+            element = quote!(<#element as #krate::Meet<#other>>::Output);
+        }
+        (element, predicates)
+    }
+
+    /// Add the `Meet` predicates required by the `Transience` to the `where`
+    /// clause of the impl.
+    fn add_meet_predicates(&mut self, variances: &[Variance], krate: &Path) {
+        let predicates: Vec<WherePredicate> = variances
+            .iter()
+            .zip(self.lifetimes.iter())
+            .flat_map(|(variance, lifetime)| self.met_element(variance, lifetime, krate).1)
+            .collect();
+        if !predicates.is_empty() {
+            let where_clause = self.impl_.make_where_clause();
+            where_clause.predicates.extend(predicates);
+        }
+    }
+
+    /// Assemble the `Transience` type for a reference to the type with the
+    /// given lifetime, which is prepended as a covariant element. Behind a
+    /// mutable reference, every lifetime of the type becomes invariant.
+    fn reference_transience(
+        &self,
+        reference: &Lifetime,
+        variances: &[Variance],
+        mutable: bool,
+        krate: &Path,
+    ) -> TokenStream2 {
+        let lifetimes = self.lifetimes.iter();
+        let elements: Vec<TokenStream2> = std::iter::once(quote!(#krate::Co<#reference>))
+            .chain(
+                variances
+                    .iter()
+                    .zip(lifetimes)
+                    .map(|(variance, lifetime)| match mutable {
+                        true => quote!(#krate::Inv<#lifetime>),
+                        false => quote!(#krate::#variance<#lifetime>),
+                    }),
+            )
+            .collect();
+        collapse_transience(&elements, krate)
+    }
+
+    /// Assert that the type really is covariant (or contravariant) in each
+    /// lifetime whose variance was inferred as such, by coercing it to a shorter
+    /// (or longer) lifetime in a function that is never called. The inference
+    /// recognizes containers by name, so this lets the compiler reject a type
+    /// that only looks covariant (such as one using a local alias named `Option`
+    /// for an invariant type).
+    fn variance_assertions(&self, name: &Ident, variances: &[Variance]) -> Result<TokenStream2> {
+        let short: Lifetime = parse_quote!('__transient_short);
+        let long: Lifetime = parse_quote!('__transient_long);
+        variances
+            .iter()
+            .zip(self.lifetimes.iter())
+            .filter_map(|(variance, lifetime)| {
+                let (from, to, ident) = match variance.0 {
+                    VarianceKind::Covariant => (&long, &short, quote!(assert_covariant)),
+                    VarianceKind::Contravariant => (&short, &long, quote!(assert_contravariant)),
+                    _ => return None,
+                };
+                let from = Replace::Lifetime(lifetime, from.clone());
+                let to = Replace::Lifetime(lifetime, to.clone());
+                Some(self.coercion(name, from, to, ident))
+            })
+            .collect()
     }
 
-    fn is_static(&self) -> bool {
-        self.lifetime.is_none()
+    /// Assert that the type really is covariant in each bound type parameter,
+    /// which is required since their `Transience` is included as-is, by
+    /// replacing it with a reference that is coerced to a shorter lifetime.
+    fn param_assertions(&self, name: &Ident) -> Result<TokenStream2> {
+        self.bound_params
+            .iter()
+            .map(|param| {
+                let from = Replace::Param(param, parse_quote!('__transient_long));
+                let to = Replace::Param(param, parse_quote!('__transient_short));
+                self.coercion(name, from, to, quote!(assert_covariant))
+            })
+            .collect()
     }
 
-    fn transience_generics(&self) -> Generics {
-        match self.lifetime.as_ref() {
-            Some(lifetime) => parse_quote!(<#lifetime>),
-            None => no_generics(),
+    /// Build a function coercing the type with one of its generic parameters
+    /// replaced by `from` to the type with it replaced by `to`, which only
+    /// compiles if the first is a subtype of the second. The bounds of the type
+    /// are repeated for both, so that each is well-formed. Fails for a bound type
+    /// parameter that is only used in an output of a bound, since the copy of
+    /// that bound can't be generalized over the lifetime of the reference.
+    fn coercion(
+        &self,
+        name: &Ident,
+        from: Replace,
+        to: Replace,
+        ident: TokenStream2,
+    ) -> Result<TokenStream2> {
+        let short: Lifetime = parse_quote!('__transient_short);
+        let long: Lifetime = parse_quote!('__transient_long);
+        let mut generics = self.original.clone();
+        let mut bounds: Vec<WherePredicate> = generics
+            .where_clause
+            .take()
+            .map(|where_clause| where_clause.predicates.into_iter().collect())
+            .unwrap_or_default();
+        let mut params: Vec<GenericParam> = vec![parse_quote!(#short), parse_quote!(#long: #short)];
+        let mut args: Vec<TokenStream2> = vec![];
+        for param in std::mem::take(&mut generics.params) {
+            match param {
+                GenericParam::Lifetime(mut param) => {
+                    let lifetime = param.lifetime.clone();
+                    args.push(quote!(#lifetime));
+                    if !param.bounds.is_empty() {
+                        let outlives = std::mem::take(&mut param.bounds);
+                        bounds.push(parse_quote!(#lifetime: #outlives));
+                    }
+                    if from.lifetime() != Some(&lifetime) {
+                        param.attrs.clear();
+                        param.colon_token = None;
+                        params.push(GenericParam::Lifetime(param));
+                    }
+                }
+                GenericParam::Type(mut param) => {
+                    let ident = &param.ident;
+                    args.push(quote!(#ident));
+                    // `?Sized` can only be written on the parameter itself
+                    let (relaxed, traits): (Vec<_>, Vec<_>) = std::mem::take(&mut param.bounds)
+                        .into_iter()
+                        .partition(|bound| {
+                            matches!(
+                                bound,
+                                TypeParamBound::Trait(bound)
+                                    if matches!(bound.modifier, TraitBoundModifier::Maybe(_))
+                            )
+                        });
+                    if !traits.is_empty() {
+                        bounds.push(parse_quote!(#ident: #(#traits)+*));
+                    }
+                    param.attrs.clear();
+                    param.colon_token = relaxed.first().map(|_| Default::default());
+                    param.bounds.extend(relaxed);
+                    param.eq_token = None;
+                    param.default = None;
+                    params.push(GenericParam::Type(param));
+                }
+                GenericParam::Const(mut param) => {
+                    let ident = &param.ident;
+                    args.push(quote!(#ident));
+                    param.attrs.clear();
+                    param.eq_token = None;
+                    param.default = None;
+                    params.push(GenericParam::Const(param));
+                }
+            }
         }
+        let ty: Type = match args.is_empty() {
+            true => parse_quote!(#name),
+            false => parse_quote!(#name<#(#args),*>),
+        };
+        let (mut from, mut to) = (from, to);
+        let from_ty = from.apply(&ty);
+        let to_ty = to.apply(&ty);
+        let (mut predicates, bounds) = match generalize_predicates(bounds, &from) {
+            Some(split) => split,
+            // for a lifetime, the impl then only applies when it is `'static`,
+            // so its variance is never relied upon
+            None => match from {
+                Replace::Lifetime(..) => return Ok(quote!()),
+                Replace::Param(param, _) => {
+                    return Err(Error::UnverifiableParam {
+                        string: param.to_string(),
+                        span: param.span(),
+                    })
+                }
+            },
+        };
+        for bound in bounds.iter() {
+            let (from_bound, to_bound) = (from.apply(bound), to.apply(bound));
+            // a bound that doesn't mention the parameter only needs to be given once
+            let same =
+                from_bound.to_token_stream().to_string() == to_bound.to_token_stream().to_string();
+            predicates.push(from_bound);
+            if !same {
+                predicates.push(to_bound);
+            }
+        }
+        Ok(quote!(
+            const _: () = {
+                #[allow(dead_code)]
+                fn #ident<#(#params),*>(value: #from_ty) -> #to_ty
+                where
+                    #(#predicates,)*
+                {
+                    value
+                }
+            };
+        ))
     }
 
     fn impl_generics(&self) -> &Generics {
         &self.impl_
     }
 
-    fn split_for_impl(&self) -> (TypeGenerics, Option<&WhereClause>) {
-        let (_, type_generics, where_clause) = self.original.split_for_impl();
-        (type_generics, where_clause)
+    fn split_for_impl(&self) -> (TypeGenerics<'_>, Option<&WhereClause>) {
+        let (_, type_generics, _) = self.original.split_for_impl();
+        (type_generics, self.impl_.where_clause.as_ref())
     }
 
-    fn static_type_generics(&self) -> TypeGenerics {
-        self.static_.split_for_impl().1
+    fn static_type_generics(&self) -> TokenStream2 {
+        match self.static_.as_slice() {
+            [] => quote!(),
+            args => quote!(<#(#args),*>),
+        }
     }
 }
 
-fn process_param(param: &mut GenericParam) -> Result<()> {
-    match param {
-        GenericParam::Lifetime(lt) => Err(Error::TooManyLifetimes(lt.span())),
-        GenericParam::Type(ty) => {
-            ty.bounds.push(static_type_bound());
-            Ok(())
+/// Collapse the elements of a `Transience` into a single type, which is
+/// `Timeless` when there are none and a tuple when there is more than one.
+fn collapse_transience(elements: &[TokenStream2], krate: &Path) -> TokenStream2 {
+    match elements {
+        [] => {
+            let variance = VarianceKind::Static.unspanned();
+            quote!(#krate::#variance)
         }
-        _ => Ok(()),
+        [element] => element.clone(),
+        elements => quote!((#(#elements),*)),
     }
 }
 
-fn process_generics(generics: Generics) -> Result<Params> {
+fn process_generics(generics: Generics, krate: &Path) -> Result<Params> {
     // no generic params == ezpz
     if generics.params.is_empty() {
         return Ok(Params::empty());
     }
     // generics for impl<...> (same as orig, but with `'static` added to any type params)
     let mut impl_generics = generics.clone();
-    let mut params_iter = impl_generics.params.iter_mut();
 
-    // generics for the `Static` type (same as orig, but `'a` replaced by `'static`)
-    let mut static_generics = vec![];
+    // generic args for the `Static` type (same as orig, but each `'a` replaced by
+    // `'static` and each bound type param `T` replaced by `T::Static`)
+    let mut static_args = vec![];
+
+    // lifetimes used to build the `Transience` type, in declaration order
+    let mut lifetimes = vec![];
+
+    // type params whose own `Transience` is included in the `Transience` type
+    let mut bound_params = vec![];
+    let mut met_params = vec![];
+
+    // the bound added to each type param, which is applied after the bounds
+    // are rewritten so that the added bounds are not rewritten themselves
+    let mut added_bounds = vec![];
 
-    // get lifetime from the first parameter
-    let lifetime = match params_iter.next().unwrap() {
-        GenericParam::Lifetime(lt) => {
-            static_generics.push(static_param());
-            Some(lt.lifetime.clone())
+    // number of elements in the `Transience` tuple so far
+    let mut elements = 0;
+
+    for param in impl_generics.params.iter_mut() {
+        match param {
+            GenericParam::Lifetime(lt) => {
+                elements += 1;
+                if elements > MAX_ELEMENTS {
+                    return Err(Error::TooManyLifetimes(lt.span()));
+                }
+                lifetimes.push(lt.lifetime.clone());
+                static_args.push(quote!('static));
+            }
+            GenericParam::Type(ty) => {
+                let ident = &ty.ident;
+                match parse_param_kind(&ty.attrs)? {
+                    ParamKind::Static => {
+                        static_args.push(quote!(#ident));
+                        added_bounds.push(static_type_bound());
+                    }
+                    kind => {
+                        static_args.push(quote!(<#ident as #krate::Transient>::Static));
+                        added_bounds.push(parse_quote!(#krate::Transient));
+                        bound_params.push(ident.clone());
+                        if let ParamKind::BoundTo(lifetime) = kind {
+                            if !lifetimes.contains(&lifetime) {
+                                let string = lifetime.to_string();
+                                let span = lifetime.span();
+                                return Err(Error::UnknownBoundLifetime { string, span });
+                            }
+                            met_params.push((ident.clone(), lifetime));
+                        } else {
+                            elements += 1;
+                            if elements > MAX_ELEMENTS {
+                                return Err(Error::TooManyLifetimes(ident.span()));
+                            }
+                        }
+                    }
+                }
+                ty.attrs.retain(|attr| !attr.path().is_ident("transient"));
+            }
+            GenericParam::Const(param_) => {
+                let ident = &param_.ident;
+                static_args.push(quote!(#ident));
+            }
+        }
+    }
+    // bounds mentioning the lifetimes (or bound type params) must also hold for
+    // the `Static` type
+    rewrite_bounds(&mut impl_generics, &lifetimes, &bound_params, krate)?;
+    let type_params = impl_generics.type_params_mut();
+    for (ty, bound) in type_params.zip(added_bounds) {
+        ty.bounds.push(bound);
+    }
+    // collect params and return
+    Ok(Params {
+        impl_: impl_generics,
+        original: generics,
+        static_: static_args,
+        lifetimes,
+        bound_params,
+        met_params,
+    })
+}
+
+/// Rewrite the bounds on the type parameters (and the predicates in the `where`
+/// clause) so that they also hold for the `Static` type, which is necessary for
+/// it to be well-formed when the type has bounds such as `T: Trait<'a>`.
+///
+/// A trait bound that mentions the type's lifetimes is replaced by a copy that
+/// is generalized over them (e.g. `for<'__a> T: Trait<'__a>`), since also adding
+/// a `T: Trait<'static>` copy would be ambiguous with the original. A lifetime
+/// that only appears in an output of the bound is not generalized, and is
+/// required to be `'static` instead. A bound that mentions a bound type
+/// parameter `T` is kept, and a copy with `T` replaced by `T::Static` (and
+/// every lifetime replaced by `'static`) is added, which is rejected if `T` is
+/// only used in an output of the bound (such as `F: Fn() -> T`) since both
+/// copies can't be satisfied at once.
+fn rewrite_bounds(
+    generics: &mut Generics,
+    lifetimes: &[Lifetime],
+    bound_params: &[Ident],
+    krate: &Path,
+) -> Result<()> {
+    let mut added = vec![];
+    for param in generics.params.iter_mut() {
+        if let GenericParam::Type(ty) = param {
+            let ident = &ty.ident;
+            let bounded_ty: Type = parse_quote!(#ident);
+            for bound in std::mem::take(&mut ty.bounds) {
+                let (kept, extra) =
+                    rewrite_bound(&bounded_ty, None, bound, lifetimes, bound_params, krate)?;
+                ty.bounds.extend(kept);
+                added.extend(extra);
+            }
         }
-        param_ => {
-            static_generics.push(param_.clone());
-            if let GenericParam::Type(ty) = param_ {
-                ty.bounds.push(static_type_bound());
+    }
+    if let Some(where_clause) = generics.where_clause.as_mut() {
+        for predicate in std::mem::take(&mut where_clause.predicates) {
+            let WherePredicate::Type(mut predicate) = predicate else {
+                where_clause.predicates.push(predicate);
+                continue;
+            };
+            for bound in std::mem::take(&mut predicate.bounds) {
+                let (kept, extra) = rewrite_bound(
+                    &predicate.bounded_ty,
+                    predicate.lifetimes.as_ref(),
+                    bound,
+                    lifetimes,
+                    bound_params,
+                    krate,
+                )?;
+                predicate.bounds.extend(kept);
+                added.extend(extra);
+            }
+            if !predicate.bounds.is_empty() {
+                where_clause
+                    .predicates
+                    .push(WherePredicate::Type(predicate));
             }
-            None
         }
+    }
+    if !added.is_empty() {
+        generics.make_where_clause().predicates.extend(added);
+    }
+    Ok(())
+}
+
+/// Rewrite a single bound `bounded_ty: bound` as described in `rewrite_bounds`,
+/// returning the bound if it should be kept along with any predicates to add.
+fn rewrite_bound(
+    bounded_ty: &Type,
+    binder: Option<&BoundLifetimes>,
+    bound: TypeParamBound,
+    lifetimes: &[Lifetime],
+    bound_params: &[Ident],
+    krate: &Path,
+) -> Result<(Option<TypeParamBound>, Vec<WherePredicate>)> {
+    if !matches!(bound, TypeParamBound::Trait(_)) {
+        return Ok((Some(bound), vec![]));
+    }
+    // a bound type param that is only used in an output (such as the `T` in
+    // `F: Fn() -> T`) would be given a second output by the `Static` copy
+    if let Some(param) = output_param(bounded_ty, &bound, bound_params) {
+        return Err(Error::UnverifiableParam {
+            string: param.to_string(),
+            span: param.span(),
+        });
+    }
+    let binder: Vec<GenericParam> = binder
+        .map(|binder| binder.lifetimes.iter().cloned().collect())
+        .unwrap_or_default();
+
+    // the copy for the `Static` type, with everything substituted
+    let mut to_static = Substitution::to_static(lifetimes, bound_params, krate);
+    let static_ty = to_static.apply(bounded_ty);
+    let static_bound = to_static.apply(&bound);
+    if to_static.replaced.is_empty() && !to_static.replaced_param {
+        return Ok((Some(bound), vec![]));
+    }
+    let static_copy = predicate(&binder, &static_ty, &static_bound);
+
+    // the copy generalized over the lifetimes, which isn't possible when the
+    // bounded type mentions a bound type param (since it differs from the
+    // bounded type of the `Static` copy, which is then not ambiguous)
+    let mut params = Substitution::to_static(&[], bound_params, krate);
+    params.apply(bounded_ty);
+    if params.replaced_param {
+        return Ok((Some(bound), vec![static_copy]));
+    }
+    // nor for a lifetime that is only used in an output of the bound (such as
+    // `Fn(&str) -> &'a str`), which a binder can't introduce; the impl is then
+    // restricted to `'a: 'static`, under which the original bound also holds
+    // for the `Static` type
+    let inputs = used_lifetimes(bounded_ty, &bound, lifetimes, false);
+    let pinned: Vec<WherePredicate> = used_lifetimes(bounded_ty, &bound, lifetimes, true)
+        .into_iter()
+        .filter(|lifetime| !inputs.contains(lifetime))
+        .map(|lifetime| parse_quote!(#lifetime: 'static))
+        .collect();
+    let mut generalize = Substitution::generalize(&inputs);
+    let general_ty = generalize.apply(bounded_ty);
+    let general_bound = generalize.apply(&bound);
+    if generalize.replaced.is_empty() {
+        return Ok(match pinned.is_empty() {
+            true => (Some(bound), vec![static_copy]),
+            false => (Some(bound), pinned),
+        });
+    }
+    let mut general_binder = binder;
+    general_binder.extend(generalize.replaced.iter().map(|lt| parse_quote!(#lt)));
+    let mut added = vec![predicate(&general_binder, &general_ty, &general_bound)];
+    added.extend(pinned);
+    // the generalized copy only covers the bound type params themselves, so the
+    // `Static` copy is still needed if they appear in the bound
+    params.apply(&bound);
+    if params.replaced_param {
+        added.push(static_copy);
+    }
+    Ok((None, added))
+}
+
+/// Find a bound type parameter that is only used in an output of the bound
+/// `ty: bound`, by replacing it with a reference and checking where its
+/// lifetime ends up.
+fn output_param<'a>(
+    ty: &Type,
+    bound: &TypeParamBound,
+    bound_params: &'a [Ident],
+) -> Option<&'a Ident> {
+    let any: Lifetime = parse_quote!('__transient_any);
+    let lifetimes = std::slice::from_ref(&any);
+    bound_params.iter().find(|param| {
+        let mut reference = Replace::Param(param, any.clone());
+        let (ty, bound) = (reference.apply(ty), reference.apply(bound));
+        !used_lifetimes(&ty, &bound, lifetimes, true).is_empty()
+            && used_lifetimes(&ty, &bound, lifetimes, false).is_empty()
+    })
+}
+
+/// Find which of the lifetimes are used in the bound `ty: bound`, optionally
+/// including its outputs (the output of `Fn(..)` sugar and the associated type
+/// bindings), in the order they are first used.
+fn used_lifetimes(
+    ty: &Type,
+    bound: &TypeParamBound,
+    lifetimes: &[Lifetime],
+    outputs: bool,
+) -> Vec<Lifetime> {
+    let mut used = UsedLifetimes {
+        lifetimes,
+        outputs,
+        found: vec![],
     };
-    // process remaining params
-    for param in params_iter {
-        static_generics.push(param.clone());
-        process_param(param)?;
+    used.visit_type_mut(&mut ty.clone());
+    used.visit_type_param_bound_mut(&mut bound.clone());
+    used.found
+}
+
+/// Collects the lifetimes used in a bound, as described in `used_lifetimes`.
+struct UsedLifetimes<'a> {
+    lifetimes: &'a [Lifetime],
+    outputs: bool,
+    found: Vec<Lifetime>,
+}
+
+impl VisitMut for UsedLifetimes<'_> {
+    fn visit_lifetime_mut(&mut self, lifetime: &mut Lifetime) {
+        if self.lifetimes.contains(lifetime) && !self.found.contains(lifetime) {
+            self.found.push(lifetime.clone());
+        }
+    }
+
+    fn visit_parenthesized_generic_arguments_mut(
+        &mut self,
+        args: &mut ParenthesizedGenericArguments,
+    ) {
+        match self.outputs {
+            true => visit_mut::visit_parenthesized_generic_arguments_mut(self, args),
+            false => args
+                .inputs
+                .iter_mut()
+                .for_each(|input| self.visit_type_mut(input)),
+        }
+    }
+
+    fn visit_generic_argument_mut(&mut self, arg: &mut GenericArgument) {
+        let is_output = matches!(
+            arg,
+            GenericArgument::AssocType(_)
+                | GenericArgument::AssocConst(_)
+                | GenericArgument::Constraint(_)
+        );
+        if self.outputs || !is_output {
+            visit_mut::visit_generic_argument_mut(self, arg);
+        }
+    }
+}
+
+/// Build the predicate `for<binder> ty: bound`, omitting an empty binder.
+///
+/// Since a bound can't have its own binder within that of the predicate, the
+/// lifetimes of a `for<'x> Trait<'x>` bound are moved to the predicate's binder.
+fn predicate(binder: &[GenericParam], ty: &Type, bound: &TypeParamBound) -> WherePredicate {
+    let mut binder = binder.to_vec();
+    let mut bound = bound.clone();
+    if let (TypeParamBound::Trait(bound), false) = (&mut bound, binder.is_empty()) {
+        if let Some(inner) = bound.lifetimes.take() {
+            binder.extend(inner.lifetimes);
+        }
+    }
+    match binder.as_slice() {
+        [] => parse_quote!(#ty: #bound),
+        binder => parse_quote!(for<#(#binder),*> #ty: #bound),
+    }
+}
+
+/// Replaces the lifetimes and bound type parameters of the deriving type, either
+/// with the corresponding parts of its `Static` type or (for lifetimes only)
+/// with fresh lifetimes that can be bound by a `for<..>` binder.
+struct Substitution<'a> {
+    lifetimes: &'a [Lifetime],
+    bound_params: &'a [Ident],
+    krate: Option<&'a Path>,
+    /// the lifetimes that have been replaced so far, as they were replaced
+    replaced: Vec<Lifetime>,
+    /// whether any bound type param has been replaced so far
+    replaced_param: bool,
+}
+
+impl<'a> Substitution<'a> {
+    fn to_static(lifetimes: &'a [Lifetime], bound_params: &'a [Ident], krate: &'a Path) -> Self {
+        Substitution {
+            lifetimes,
+            bound_params,
+            krate: Some(krate),
+            replaced: vec![],
+            replaced_param: false,
+        }
+    }
+
+    fn generalize(lifetimes: &'a [Lifetime]) -> Self {
+        Substitution {
+            lifetimes,
+            bound_params: &[],
+            krate: None,
+            replaced: vec![],
+            replaced_param: false,
+        }
+    }
+
+    /// Apply the substitution to a copy of the given type or bound.
+    fn apply<T: Clone + Substitutable>(&mut self, item: &T) -> T {
+        let mut item = item.clone();
+        item.substitute(self);
+        item
+    }
+}
+
+/// Items that a `Substitution` can be applied to.
+trait Substitutable {
+    fn substitute(&mut self, substitution: &mut Substitution<'_>);
+}
+
+impl Substitutable for Type {
+    fn substitute(&mut self, substitution: &mut Substitution<'_>) {
+        substitution.visit_type_mut(self)
+    }
+}
+
+impl Substitutable for TypeParamBound {
+    fn substitute(&mut self, substitution: &mut Substitution<'_>) {
+        substitution.visit_type_param_bound_mut(self)
+    }
+}
+
+impl VisitMut for Substitution<'_> {
+    fn visit_lifetime_mut(&mut self, lifetime: &mut Lifetime) {
+        if !self.lifetimes.contains(lifetime) {
+            return;
+        }
+        *lifetime = match self.krate {
+            Some(_) => Lifetime::new("'static", lifetime.span()),
+            None => Lifetime::new(&format!("'__transient_{}", lifetime.ident), lifetime.span()),
+        };
+        if !self.replaced.contains(lifetime) {
+            self.replaced.push(lifetime.clone());
+        }
+    }
+
+    fn visit_type_mut(&mut self, ty: &mut Type) {
+        if let (Type::Path(path), Some(krate)) = (&*ty, self.krate) {
+            if let Some(ident) = path.path.get_ident().filter(|_| path.qself.is_none()) {
+                if self.bound_params.contains(ident) {
+                    *ty = parse_quote!(<#ident as #krate::Transient>::Static);
+                    self.replaced_param = true;
+                    return;
+                }
+            }
+        }
+        visit_mut::visit_type_mut(self, ty);
+    }
+}
+
+/// How a type parameter is treated, as set by the `#[transient(...)]` attribute
+enum ParamKind {
+    /// unmarked, so the parameter must be `'static`
+    Static,
+    /// `#[transient(bound)]`
+    Bound,
+    /// `#[transient(bound = 'a)]`
+    BoundTo(Lifetime),
+}
+
+/// Check whether a type parameter is marked with `#[transient(bound)]` or
+/// `#[transient(bound = 'a)]`.
+fn parse_param_kind(attrs: &[Attribute]) -> Result<ParamKind> {
+    let mut kind = ParamKind::Static;
+    for attr in attrs.iter() {
+        if attr.path().is_ident("transient") {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("bound") {
+                    kind = match meta.input.peek(Token![=]) {
+                        true => ParamKind::BoundTo(meta.value()?.parse()?),
+                        false => ParamKind::Bound,
+                    };
+                    Ok(())
+                } else {
+                    Err(Error::UnexpectedParamOption(meta.path.span()).into())
+                }
+            })?;
+        }
+    }
+    Ok(kind)
+}
+
+/// Split the trait bounds that would be changed by the replacement out of the
+/// predicates, each generalized over the replaced lifetime instead (e.g.
+/// `for<'__transient_any> T: Trait<'__transient_any>`), since giving such a
+/// bound for two different lifetimes would be ambiguous. Returns `None` if the
+/// replacement only changes an output of one of the bounds, which a binder
+/// can't introduce.
+fn generalize_predicates(
+    bounds: Vec<WherePredicate>,
+    replace: &Replace,
+) -> Option<(Vec<WherePredicate>, Vec<WherePredicate>)> {
+    let any: Lifetime = parse_quote!('__transient_any);
+    let mut generalize = replace.with_lifetime(&any);
+    let lifetimes = std::slice::from_ref(&any);
+    let mut general = vec![];
+    let mut rest = vec![];
+    for bound in bounds {
+        let WherePredicate::Type(mut typed) = bound else {
+            rest.push(bound);
+            continue;
+        };
+        let binder: Vec<GenericParam> = typed
+            .lifetimes
+            .as_ref()
+            .map(|binder| binder.lifetimes.iter().cloned().collect())
+            .unwrap_or_default();
+        for bound in std::mem::take(&mut typed.bounds) {
+            let general_ty = generalize.apply(&typed.bounded_ty);
+            let general_bound = generalize.apply(&bound);
+            let used = used_lifetimes(&general_ty, &general_bound, lifetimes, true);
+            if !matches!(bound, TypeParamBound::Trait(_)) || used.is_empty() {
+                typed.bounds.push(bound);
+                continue;
+            }
+            if used_lifetimes(&general_ty, &general_bound, lifetimes, false).is_empty() {
+                return None;
+            }
+            let mut binder = binder.clone();
+            binder.push(parse_quote!(#any));
+            general.push(predicate(&binder, &general_ty, &general_bound));
+        }
+        if !typed.bounds.is_empty() {
+            rest.push(WherePredicate::Type(typed));
+        }
+    }
+    Some((general, rest))
+}
+
+/// Replaces one of the lifetimes of the deriving type with another lifetime (or
+/// one of its type parameters `T` with a reference `&'x T`), which is used to
+/// build the types compared by the variance assertions.
+enum Replace<'a> {
+    Lifetime(&'a Lifetime, Lifetime),
+    Param(&'a Ident, Lifetime),
+}
+
+impl Replace<'_> {
+    /// Get the lifetime being replaced, if any.
+    fn lifetime(&self) -> Option<&Lifetime> {
+        match self {
+            Replace::Lifetime(target, _) => Some(target),
+            Replace::Param(..) => None,
+        }
+    }
+
+    /// Get the same replacement using a different lifetime.
+    fn with_lifetime(&self, lifetime: &Lifetime) -> Self {
+        match *self {
+            Replace::Lifetime(target, _) => Replace::Lifetime(target, lifetime.clone()),
+            Replace::Param(target, _) => Replace::Param(target, lifetime.clone()),
+        }
+    }
+
+    /// Apply the replacement to a copy of the given type or predicate.
+    fn apply<T: Clone + Replaceable>(&mut self, item: &T) -> T {
+        let mut item = item.clone();
+        item.replace(self);
+        item
+    }
+}
+
+/// Items that a `Replace` can be applied to.
+trait Replaceable {
+    fn replace(&mut self, replace: &mut Replace<'_>);
+}
+
+impl Replaceable for Type {
+    fn replace(&mut self, replace: &mut Replace<'_>) {
+        replace.visit_type_mut(self)
+    }
+}
+
+impl Replaceable for TypeParamBound {
+    fn replace(&mut self, replace: &mut Replace<'_>) {
+        replace.visit_type_param_bound_mut(self)
+    }
+}
+
+impl Replaceable for WherePredicate {
+    fn replace(&mut self, replace: &mut Replace<'_>) {
+        replace.visit_where_predicate_mut(self)
+    }
+}
+
+impl VisitMut for Replace<'_> {
+    fn visit_lifetime_mut(&mut self, lifetime: &mut Lifetime) {
+        if let Replace::Lifetime(target, with) = self {
+            if lifetime == *target {
+                *lifetime = with.clone();
+            }
+        }
+    }
+
+    fn visit_type_mut(&mut self, ty: &mut Type) {
+        if let (Type::Path(path), Replace::Param(target, with)) = (&*ty, &*self) {
+            if path.qself.is_none() && path.path.is_ident(*target) {
+                *ty = parse_quote!(&#with #target);
+                return;
+            }
+        }
+        visit_mut::visit_type_mut(self, ty);
     }
-    // collect params and return
-    Ok(Params::new(
-        lifetime,
-        generics,
-        impl_generics,
-        static_generics,
-    ))
 }
 
 // === ERRORS === //
@@ -364,15 +1553,52 @@ type Result<T> = std::result::Result<T, Error>;
 enum Error {
     #[error(transparent)]
     Syn(#[from] SynError),
-    #[error("Only `struct`'s are supported!")]
-    NotAStruct(Span),
+    #[error("Only `struct`'s and `enum`'s are supported!")]
+    NotAStructOrEnum(Span),
+    #[error(
+        "Unexpected option! The supported options are `crate = path`, `references`, \n\
+        and `strict`\n "
+    )]
+    UnexpectedOption(Span),
+    #[error(
+        "The `references` option cannot be combined with type parameters marked \n\
+        with `#[transient(bound)]`, since their `Transience` cannot be made \n\
+        invariant behind a mutable reference!\n "
+    )]
+    ReferencesWithBoundParams(Span),
+    #[error(
+        "Unexpected option! The only supported option for a type parameter \n\
+        is `bound`\n "
+    )]
+    UnexpectedParamOption(Span),
     #[error("Expected an identifier!")]
     ExpectedIdent(Span),
-    #[error("At most one lifetime parameter is allowed!")]
+    #[error(
+        "At most {MAX_ELEMENTS} lifetime parameters are allowed, including one for \n\
+        each type parameter marked with `#[transient(bound)]` and one for the \n\
+        reference with `#[transient(references)]`!\n "
+    )]
     TooManyLifetimes(Span),
     #[error("A variance cannot be requested for a struct without \na lifetime parameter!\n ")]
     StaticTypeWithVariance(Span),
-    #[error("Duplicate variance specification! '{old}' replaced by '{new}'\n ")]
+    #[error("The variance was declared for `{string}`, which is not a lifetime \nparameter of this type!\n ")]
+    UnknownLifetime { string: String, span: Span },
+    #[error("The type parameter is bound to `{string}`, which is not a lifetime \nparameter of this type!\n ")]
+    UnknownBoundLifetime { string: String, span: Span },
+    #[error(
+        "The variance of `{string}` must be declared, since the type is marked with \n\
+        `#[transient(strict)]`! Add a `variance` attribute to one of the fields \n\
+        (e.g. '#[variance({string} = inv)]').\n "
+    )]
+    MissingVariance { string: String, span: Span },
+    #[error("The lifetime `{string}` is used in a field, but is not a lifetime \nparameter of this type!\n ")]
+    UndeclaredLifetime { string: String, span: Span },
+    #[error(
+        "An elided lifetime can only be used in a field of a type with exactly \n\
+        one lifetime parameter! Name the lifetime explicitly instead.\n "
+    )]
+    ElidedLifetime(Span),
+    #[error("Duplicate variance specification! '{old}' conflicts with '{new}'\n ")]
     DuplicateVariance { old: Variance, new: Variance },
     #[error(
         "Unexpected variance argument '{string}'! The valid options are: \n{:?}\n ",
@@ -385,6 +1611,35 @@ enum Error {
         for the `transient::Transient` trait.\n "
     )]
     UnsafeVariance { string: String, span: Span },
+    #[error(
+        "The variance cannot be inferred for a field containing a raw pointer or \n\
+        `UnsafeCell`! Declare the variance explicitly (e.g. '#[variance(inv)]') after \n\
+        reviewing the safety docs for the `transient::Transient` trait.\n "
+    )]
+    UninferableVariance(Span),
+    #[error(
+        "The declared variance '{declared}' contradicts the fields, in which `{string}` \n\
+        is {proven}! Add `unchecked` to the attribute (e.g. '#[variance(unsafe_co, \n\
+        unchecked)]') to skip this check after reviewing the safety docs for the \n\
+        `transient::Transient` trait.\n "
+    )]
+    ContradictedVariance {
+        declared: Variance,
+        string: String,
+        proven: Variance,
+    },
+    #[error(
+        "A type parameter marked with `#[transient(bound)]` can only be used in \n\
+        covariant positions (such as `T`, `&'a T`, or `Vec<T>`), since its \n\
+        `Transience` is included as-is!\n "
+    )]
+    NonCovariantParam(Span),
+    #[error(
+        "The covariance of `{string}` cannot be verified, since it is only used in \n\
+        an output of a bound (such as `F: Fn() -> {string}`)! Remove the \n\
+        `#[transient(bound)]` attribute from it.\n "
+    )]
+    UnverifiableParam { string: String, span: Span },
 }
 
 impl From<Error> for SynError {
@@ -392,13 +1647,25 @@ impl From<Error> for SynError {
         let msg = value.to_string();
         let span = match value {
             Error::Syn(err) => err.span(),
-            Error::NotAStruct(span) => span,
+            Error::NotAStructOrEnum(span) => span,
+            Error::UnexpectedOption(span) => span,
+            Error::ReferencesWithBoundParams(span) => span,
+            Error::UnexpectedParamOption(span) => span,
             Error::ExpectedIdent(span) => span,
             Error::TooManyLifetimes(span) => span,
             Error::StaticTypeWithVariance(span) => span,
+            Error::UnknownLifetime { span, .. } => span,
+            Error::UnknownBoundLifetime { span, .. } => span,
+            Error::MissingVariance { span, .. } => span,
+            Error::UndeclaredLifetime { span, .. } => span,
+            Error::ElidedLifetime(span) => span,
             Error::DuplicateVariance { new, .. } => new.span(),
             Error::UnexpectedVariance { span, .. } => span,
             Error::UnsafeVariance { span, .. } => span,
+            Error::UninferableVariance(span) => span,
+            Error::ContradictedVariance { declared, .. } => declared.span(),
+            Error::NonCovariantParam(span) => span,
+            Error::UnverifiableParam { span, .. } => span,
         };
         SynError::new(span, msg)
     }
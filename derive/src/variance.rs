@@ -0,0 +1,430 @@
+//! Infers the variance of a type with respect to each of its lifetime parameters
+//! by walking the types of its fields, following the same rules as the compiler.
+use crate::VarianceKind::{self, Contravariant, Covariant, Invariant};
+use crate::{Error, Result, Variance};
+use proc_macro2::Span;
+use syn::{
+    spanned::Spanned, BoundLifetimes, Data, Fields, GenericArgument, GenericParam, Ident, Lifetime,
+    PathArguments, ReturnType, Type, TypeParamBound,
+};
+
+/// Container types from `std` that are known to be covariant in their type
+/// parameters, along with the number of type parameters they accept. This
+/// includes `PhantomData`, which the compiler treats exactly like its parameter.
+const COVARIANT_CONTAINERS: [(&str, usize); 7] = [
+    ("PhantomData", 1),
+    ("Box", 1),
+    ("Vec", 1),
+    ("Option", 1),
+    ("Result", 2),
+    ("Rc", 1),
+    ("Arc", 1),
+];
+
+/// Interior mutability containers from `std`, which are known to be invariant
+/// in their type parameters, along with the number of type parameters they
+/// accept. Uses inside these are invariant even when checking a declared
+/// variance, unlike those inside types whose variance is unknown.
+const INVARIANT_CONTAINERS: [(&str, usize); 4] =
+    [("Cell", 1), ("RefCell", 1), ("Mutex", 1), ("RwLock", 1)];
+
+/// Infer the variance with respect to each of the provided lifetimes from the
+/// fields of the struct or enum, returning one entry per lifetime in the same
+/// order. Lifetimes that never appear in a field are assumed to be invariant.
+///
+/// Fails if a field contains a raw pointer or an `UnsafeCell`, since these may
+/// hide borrows whose variance cannot be determined from the type alone.
+pub(crate) fn infer(data: &Data, lifetimes: &[Lifetime]) -> Result<Vec<VarianceKind>> {
+    if lifetimes.is_empty() {
+        return Ok(vec![]);
+    }
+    let inference = Inference::new(lifetimes, &[]).visit_data(data);
+    if let Some(span) = inference.unsupported {
+        return Err(Error::UninferableVariance(span));
+    }
+    Ok(inference
+        .uses
+        .into_iter()
+        .map(|used| used.unwrap_or(Invariant))
+        .collect())
+}
+
+/// Check that every lifetime used in the fields of the struct or enum is either
+/// one of the provided lifetimes, `'static`, or bound by an enclosing `for<..>`
+/// binder or `fn` pointer, and that each of the provided type parameters (those
+/// marked with `#[transient(bound)]`) is only used in covariant positions, since
+/// their `Transience` is included as-is in the `Transience` of the deriving type.
+pub(crate) fn check(data: &Data, lifetimes: &[Lifetime], params: &[Ident]) -> Result<()> {
+    let inference = Inference::new(lifetimes, params).visit_data(data);
+    if let Some(lifetime) = inference.undeclared {
+        let string = lifetime.to_string();
+        let span = lifetime.span();
+        return Err(Error::UndeclaredLifetime { string, span });
+    }
+    if let Some(span) = inference.elided {
+        return Err(Error::ElidedLifetime(span));
+    }
+    match inference.invalid_param {
+        Some(span) => Err(Error::NonCovariantParam(span)),
+        None => Ok(()),
+    }
+}
+
+/// Check that each declared variance is consistent with the uses of its lifetime
+/// whose variance is certain, failing if (for example) a lifetime is declared
+/// covariant but appears behind a `&mut` or in the argument of a `fn` pointer.
+///
+/// Uses inside types whose variance is unknown (such as generic types defined
+/// elsewhere) are skipped, since these are the situations in which declaring
+/// the variance is allowed to override the inference.
+pub(crate) fn verify(data: &Data, lifetimes: &[Lifetime], declared: &[Variance]) -> Result<()> {
+    let mut inference = Inference::new(lifetimes, &[]);
+    inference.strict = true;
+    let inference = inference.visit_data(data);
+    let uses = inference.uses.into_iter().zip(lifetimes).zip(declared);
+    for ((proven, lifetime), declared) in uses {
+        match (proven, declared.0) {
+            (Some(proven), Covariant | Contravariant) if proven != declared.0 => {
+                let declared = declared.clone();
+                let string = lifetime.to_string();
+                let proven = proven.unspanned();
+                return Err(Error::ContradictedVariance {
+                    declared,
+                    string,
+                    proven,
+                });
+            }
+            _ => {}
+        }
+    }
+    Ok(())
+}
+
+/// Accumulates the variance of each lifetime over every position it is used in.
+struct Inference<'l> {
+    lifetimes: &'l [Lifetime],
+    uses: Vec<Option<VarianceKind>>,
+    /// span of the first type encountered whose variance cannot be inferred
+    unsupported: Option<Span>,
+    /// type parameters whose uses must all be covariant
+    params: &'l [Ident],
+    /// span of the first non-covariant use of one of the `params`
+    invalid_param: Option<Span>,
+    /// lifetimes bound by the enclosing `for<..>` binders
+    bound: Vec<Lifetime>,
+    /// number of enclosing `fn` pointers (or `Fn(..)` bounds), within which
+    /// elided lifetimes are higher-ranked rather than referring to the type
+    fn_depth: usize,
+    /// first lifetime used in a field that isn't declared on the type
+    undeclared: Option<Lifetime>,
+    /// span of the first elided lifetime that can't be resolved to the type's
+    /// only lifetime parameter
+    elided: Option<Span>,
+    /// only record the uses whose variance is certain, skipping those inside
+    /// types whose variance is unknown instead of treating them as invariant
+    strict: bool,
+}
+
+impl<'l> Inference<'l> {
+    fn new(lifetimes: &'l [Lifetime], params: &'l [Ident]) -> Self {
+        Inference {
+            lifetimes,
+            uses: vec![None; lifetimes.len()],
+            unsupported: None,
+            params,
+            invalid_param: None,
+            bound: vec![],
+            fn_depth: 0,
+            undeclared: None,
+            elided: None,
+            strict: false,
+        }
+    }
+
+    fn visit_data(mut self, data: &Data) -> Self {
+        match data {
+            Data::Struct(data) => self.visit_fields(&data.fields),
+            Data::Enum(data) => {
+                for variant in data.variants.iter() {
+                    self.visit_fields(&variant.fields);
+                }
+            }
+            Data::Union(_) => {}
+        }
+        self
+    }
+
+    fn visit_fields(&mut self, fields: &Fields) {
+        for field in fields.iter() {
+            self.visit_type(&field.ty, Covariant);
+        }
+    }
+
+    /// Record a use of the lifetime in a position with the given variance.
+    fn visit_lifetime(&mut self, lifetime: &Lifetime, position: VarianceKind) {
+        if lifetime.ident == "_" {
+            return self.visit_elided(lifetime.span(), position);
+        }
+        let Some(i) = self.lifetimes.iter().position(|lt| lt == lifetime) else {
+            if lifetime.ident != "static" && !self.bound.contains(lifetime) {
+                self.undeclared.get_or_insert_with(|| lifetime.clone());
+            }
+            return;
+        };
+        self.record(i, position);
+    }
+
+    /// Record a use of an elided (or `'_`) lifetime, which refers to the type's
+    /// lifetime parameter if it has exactly one.
+    fn visit_elided(&mut self, span: Span, position: VarianceKind) {
+        if self.fn_depth > 0 {
+            return;
+        }
+        match self.lifetimes.len() {
+            1 => self.record(0, position),
+            _ => {
+                self.elided.get_or_insert(span);
+            }
+        }
+    }
+
+    /// Record a use of the `i`th lifetime in a position with the given variance.
+    fn record(&mut self, i: usize, position: VarianceKind) {
+        self.uses[i] = match self.uses[i].take() {
+            Some(previous) => Some(previous.meet(position)),
+            None => Some(position),
+        };
+    }
+
+    /// Record a use of one of the checked type parameters, if `ident` is one.
+    fn visit_param(&mut self, ident: &Ident, position: VarianceKind) {
+        if position != Covariant && self.params.contains(ident) {
+            self.invalid_param.get_or_insert(ident.span());
+        }
+    }
+
+    /// Conservatively mark every lifetime as invariant (and any checked type
+    /// parameters as misused); this is used for types whose contents cannot be
+    /// inspected (such as macro invocations).
+    fn poison(&mut self, span: Span) {
+        if self.strict {
+            return;
+        }
+        self.uses.fill(Some(Invariant));
+        if !self.params.is_empty() {
+            self.invalid_param.get_or_insert(span);
+        }
+    }
+
+    /// Record a type whose variance cannot be inferred.
+    fn unsupported(&mut self, span: Span) {
+        self.unsupported.get_or_insert(span);
+    }
+
+    fn visit_type(&mut self, ty: &Type, position: VarianceKind) {
+        match ty {
+            Type::Reference(ty) => {
+                match ty.lifetime.as_ref() {
+                    Some(lifetime) => self.visit_lifetime(lifetime, position),
+                    None => self.visit_elided(ty.and_token.span(), position),
+                }
+                match ty.mutability {
+                    Some(_) => self.visit_type(&ty.elem, Invariant),
+                    None => self.visit_type(&ty.elem, position),
+                }
+            }
+            // `*const T` is covariant in `T`, but the pointer may hide borrows
+            Type::Ptr(ty) if self.strict && ty.mutability.is_none() => {
+                self.visit_type(&ty.elem, position)
+            }
+            Type::Ptr(ty) => {
+                self.unsupported(ty.span());
+                self.visit_type(&ty.elem, Invariant);
+            }
+            Type::BareFn(ty) => {
+                let n_bound = self.bind(ty.lifetimes.as_ref());
+                self.fn_depth += 1;
+                let flipped = position.compose(Contravariant);
+                for arg in ty.inputs.iter() {
+                    self.visit_type(&arg.ty, flipped);
+                }
+                if let ReturnType::Type(_, output) = &ty.output {
+                    self.visit_type(output, position);
+                }
+                self.fn_depth -= 1;
+                self.bound.truncate(self.bound.len() - n_bound);
+            }
+            Type::Array(ty) => self.visit_type(&ty.elem, position),
+            Type::Slice(ty) => self.visit_type(&ty.elem, position),
+            Type::Group(ty) => self.visit_type(&ty.elem, position),
+            Type::Paren(ty) => self.visit_type(&ty.elem, position),
+            Type::Tuple(ty) => {
+                for elem in ty.elems.iter() {
+                    self.visit_type(elem, position);
+                }
+            }
+            Type::TraitObject(ty) => {
+                for bound in ty.bounds.iter() {
+                    self.visit_bound(bound, position);
+                }
+            }
+            Type::Path(ty) => {
+                match ty.qself.as_ref() {
+                    // the projection may normalize to a type without the lifetime
+                    Some(_) if self.strict => return,
+                    Some(qself) => self.visit_type(&qself.ty, Invariant),
+                    None => {}
+                }
+                self.visit_path(&ty.path, position);
+            }
+            Type::Never(_) => {}
+            _ => self.poison(ty.span()),
+        }
+    }
+
+    fn visit_bound(&mut self, bound: &TypeParamBound, position: VarianceKind) {
+        match bound {
+            // `dyn Trait + 'a` is covariant in `'a`
+            TypeParamBound::Lifetime(lifetime) => self.visit_lifetime(lifetime, position),
+            // but any lifetimes in the trait itself are invariant
+            TypeParamBound::Trait(bound) => {
+                let n_bound = self.bind(bound.lifetimes.as_ref());
+                self.visit_path(&bound.path, Invariant);
+                self.bound.truncate(self.bound.len() - n_bound);
+            }
+            _ => self.poison(bound.span()),
+        }
+    }
+
+    /// Push the lifetimes introduced by a `for<..>` binder, returning how many
+    /// were added so they can be popped once the binder goes out of scope.
+    fn bind(&mut self, binder: Option<&BoundLifetimes>) -> usize {
+        let Some(binder) = binder else {
+            return 0;
+        };
+        let len = self.bound.len();
+        for param in binder.lifetimes.iter() {
+            if let GenericParam::Lifetime(param) = param {
+                self.bound.push(param.lifetime.clone());
+            }
+        }
+        self.bound.len() - len
+    }
+
+    fn visit_path(&mut self, path: &syn::Path, position: VarianceKind) {
+        let Some(last) = path.segments.last() else {
+            return;
+        };
+        // `Self` hides every lifetime of the type, in positions that can't be
+        // seen from here
+        if path.segments[0].ident == "Self" {
+            return self.poison(path.span());
+        }
+        // lifetimes and types in any leading segments are treated as unknown
+        for segment in path.segments.iter().take(path.segments.len() - 1) {
+            self.visit_param(&segment.ident, Invariant);
+            if !self.strict {
+                self.visit_path_arguments(&segment.arguments, Invariant);
+            }
+        }
+        if path.segments.len() == 1 && last.arguments.is_none() {
+            self.visit_param(&last.ident, position);
+        }
+        let ident = last.ident.to_string();
+        if ident == "UnsafeCell" {
+            self.unsupported(path.span());
+            return self.visit_path_arguments(&last.arguments, Invariant);
+        }
+        let args: Vec<_> = match &last.arguments {
+            PathArguments::AngleBracketed(args) => args.args.iter().collect(),
+            _ => vec![],
+        };
+        // the `Co`, `Contra`, and `Inv` markers from this crate
+        if let (Some(kind), [GenericArgument::Lifetime(lifetime)], true) = (
+            marker_variance(&ident),
+            args.as_slice(),
+            is_marker_path(path),
+        ) {
+            return self.visit_lifetime(lifetime, position.compose(kind));
+        }
+        // containers from `std` whose variance in their type parameters is known,
+        // which a path into any other module (or a local alias) may only mimic
+        let is_container = |containers: &[(&str, usize)]| {
+            containers.iter().any(|(name, n_params)| {
+                *name == ident
+                    && is_std_path(path)
+                    && args.len() == *n_params
+                    && args
+                        .iter()
+                        .all(|arg| matches!(arg, GenericArgument::Type(_)))
+            })
+        };
+        let position = match is_container(&COVARIANT_CONTAINERS) {
+            true => position,
+            false if self.strict && !is_container(&INVARIANT_CONTAINERS) => return,
+            false => Invariant,
+        };
+        self.visit_path_arguments(&last.arguments, position);
+    }
+
+    fn visit_path_arguments(&mut self, arguments: &PathArguments, position: VarianceKind) {
+        match arguments {
+            PathArguments::None => {}
+            PathArguments::AngleBracketed(args) => {
+                for arg in args.args.iter() {
+                    match arg {
+                        GenericArgument::Lifetime(lifetime) => {
+                            self.visit_lifetime(lifetime, position)
+                        }
+                        GenericArgument::Type(ty) => self.visit_type(ty, position),
+                        GenericArgument::AssocType(assoc) => self.visit_type(&assoc.ty, Invariant),
+                        GenericArgument::Const(_) => {}
+                        _ => self.poison(arg.span()),
+                    }
+                }
+            }
+            // `Fn(&'a str) -> &'b str` style sugar, which only appears in trait
+            // bounds and is therefore already in an invariant position
+            PathArguments::Parenthesized(args) => {
+                self.fn_depth += 1;
+                for input in args.inputs.iter() {
+                    self.visit_type(input, Invariant);
+                }
+                if let ReturnType::Type(_, output) = &args.output {
+                    self.visit_type(output, Invariant);
+                }
+                self.fn_depth -= 1;
+            }
+        }
+    }
+}
+
+/// Check whether the path is either a single segment or a path into `std`,
+/// `core`, or `alloc`.
+fn is_std_path(path: &syn::Path) -> bool {
+    match path.segments.len() {
+        1 => path.leading_colon.is_none(),
+        _ => ["std", "core", "alloc"]
+            .iter()
+            .any(|krate| path.segments[0].ident == krate),
+    }
+}
+
+/// Check whether the path is either a single segment or a path into this crate,
+/// under the name it is usually imported as.
+fn is_marker_path(path: &syn::Path) -> bool {
+    match path.segments.len() {
+        1 => path.leading_colon.is_none(),
+        _ => path.segments[0].ident == "transient",
+    }
+}
+
+/// Get the variance represented by one of this crate's marker types.
+fn marker_variance(ident: &str) -> Option<VarianceKind> {
+    match ident {
+        "Co" => Some(Covariant),
+        "Contra" => Some(Contravariant),
+        "Inv" => Some(Invariant),
+        _ => None,
+    }
+}
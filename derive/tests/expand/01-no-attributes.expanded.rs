@@ -3,6 +3,7 @@ use transient_derive::Transient;
 struct NoGenerics {
     value1: String,
 }
+#[automatically_derived]
 unsafe impl ::transient::Transient for NoGenerics {
     type Static = NoGenerics;
     type Transience = ::transient::Timeless;
@@ -10,13 +11,23 @@ unsafe impl ::transient::Transient for NoGenerics {
 struct LifetimeOnly<'a> {
     value1: &'a str,
 }
+#[automatically_derived]
 unsafe impl<'a> ::transient::Transient for LifetimeOnly<'a> {
     type Static = LifetimeOnly<'static>;
-    type Transience = ::transient::Inv<'a>;
+    type Transience = ::transient::Co<'a>;
 }
+const _: () = {
+    #[allow(dead_code)]
+    fn assert_covariant<'__transient_short, '__transient_long: '__transient_short>(
+        value: LifetimeOnly<'__transient_long>,
+    ) -> LifetimeOnly<'__transient_short> {
+        value
+    }
+};
 struct TypeOnly<T> {
     value: T,
 }
+#[automatically_derived]
 unsafe impl<T: 'static> ::transient::Transient for TypeOnly<T> {
     type Static = TypeOnly<T>;
     type Transience = ::transient::Timeless;
@@ -24,16 +35,39 @@ unsafe impl<T: 'static> ::transient::Transient for TypeOnly<T> {
 struct TypeAndLifetime<'a, T> {
     value: &'a T,
 }
+#[automatically_derived]
 unsafe impl<'a, T: 'static> ::transient::Transient for TypeAndLifetime<'a, T> {
     type Static = TypeAndLifetime<'static, T>;
-    type Transience = ::transient::Inv<'a>;
+    type Transience = ::transient::Co<'a>;
 }
+const _: () = {
+    #[allow(dead_code)]
+    fn assert_covariant<'__transient_short, '__transient_long: '__transient_short, T>(
+        value: TypeAndLifetime<'__transient_long, T>,
+    ) -> TypeAndLifetime<'__transient_short, T> {
+        value
+    }
+};
 struct TypesAndLifetime<'a, T1, T2> {
     value1: &'a T1,
     value2: T2,
 }
+#[automatically_derived]
 unsafe impl<'a, T1: 'static, T2: 'static> ::transient::Transient
 for TypesAndLifetime<'a, T1, T2> {
     type Static = TypesAndLifetime<'static, T1, T2>;
-    type Transience = ::transient::Inv<'a>;
+    type Transience = ::transient::Co<'a>;
 }
+const _: () = {
+    #[allow(dead_code)]
+    fn assert_covariant<
+        '__transient_short,
+        '__transient_long: '__transient_short,
+        T1,
+        T2,
+    >(
+        value: TypesAndLifetime<'__transient_long, T1, T2>,
+    ) -> TypesAndLifetime<'__transient_short, T1, T2> {
+        value
+    }
+};
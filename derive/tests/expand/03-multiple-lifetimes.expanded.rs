@@ -0,0 +1,38 @@
+//! Verifies that types with multiple lifetimes expand to a tuple `Transience`
+use transient_derive::Transient;
+struct TwoLifetimes<'a, 'b> {
+    value1: &'a str,
+    value2: &'b str,
+}
+#[automatically_derived]
+unsafe impl<'a, 'b> ::transient::Transient for TwoLifetimes<'a, 'b> {
+    type Static = TwoLifetimes<'static, 'static>;
+    type Transience = (::transient::Co<'a>, ::transient::Co<'b>);
+}
+const _: () = {
+    #[allow(dead_code)]
+    fn assert_covariant<'__transient_short, '__transient_long: '__transient_short, 'b>(
+        value: TwoLifetimes<'__transient_long, 'b>,
+    ) -> TwoLifetimes<'__transient_short, 'b> {
+        value
+    }
+};
+const _: () = {
+    #[allow(dead_code)]
+    fn assert_covariant<'__transient_short, '__transient_long: '__transient_short, 'a>(
+        value: TwoLifetimes<'a, '__transient_long>,
+    ) -> TwoLifetimes<'a, '__transient_short> {
+        value
+    }
+};
+struct TwoLifetimesAndType<'a, 'b, T> {
+    #[variance(unsafe_covariant)]
+    value1: &'a T,
+    value2: &'b T,
+}
+#[automatically_derived]
+unsafe impl<'a, 'b, T: 'static> ::transient::Transient
+for TwoLifetimesAndType<'a, 'b, T> {
+    type Static = TwoLifetimesAndType<'static, 'static, T>;
+    type Transience = (::transient::Co<'a>, ::transient::Co<'b>);
+}
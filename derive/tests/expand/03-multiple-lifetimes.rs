@@ -0,0 +1,15 @@
+//! Verifies that types with multiple lifetimes expand to a tuple `Transience`
+use transient_derive::Transient;
+
+#[derive(Transient)]
+struct TwoLifetimes<'a, 'b> {
+    value1: &'a str,
+    value2: &'b str,
+}
+
+#[derive(Transient)]
+struct TwoLifetimesAndType<'a, 'b, T> {
+    #[variance(unsafe_covariant)]
+    value1: &'a T,
+    value2: &'b T,
+}
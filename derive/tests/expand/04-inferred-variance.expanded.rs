@@ -0,0 +1,149 @@
+//! Verifies that the variance is inferred from the field types as expected
+use std::cell::Cell;
+use transient::{Co, Contra};
+use transient_derive::Transient;
+struct SharedRef<'a, T> {
+    value: &'a [Option<Box<T>>],
+}
+#[automatically_derived]
+unsafe impl<'a, T: 'static> ::transient::Transient for SharedRef<'a, T> {
+    type Static = SharedRef<'static, T>;
+    type Transience = ::transient::Co<'a>;
+}
+const _: () = {
+    #[allow(dead_code)]
+    fn assert_covariant<'__transient_short, '__transient_long: '__transient_short, T>(
+        value: SharedRef<'__transient_long, T>,
+    ) -> SharedRef<'__transient_short, T> {
+        value
+    }
+};
+struct MutRef<'a, 'b> {
+    value: &'a mut &'b str,
+}
+#[automatically_derived]
+unsafe impl<'a, 'b> ::transient::Transient for MutRef<'a, 'b> {
+    type Static = MutRef<'static, 'static>;
+    type Transience = (::transient::Co<'a>, ::transient::Inv<'b>);
+}
+const _: () = {
+    #[allow(dead_code)]
+    fn assert_covariant<'__transient_short, '__transient_long: '__transient_short, 'b>(
+        value: MutRef<'__transient_long, 'b>,
+    ) -> MutRef<'__transient_short, 'b> {
+        value
+    }
+};
+struct Func<'a, 'b> {
+    func: fn(&'a str) -> &'b str,
+}
+#[automatically_derived]
+unsafe impl<'a, 'b> ::transient::Transient for Func<'a, 'b> {
+    type Static = Func<'static, 'static>;
+    type Transience = (::transient::Contra<'a>, ::transient::Co<'b>);
+}
+const _: () = {
+    #[allow(dead_code)]
+    fn assert_contravariant<
+        '__transient_short,
+        '__transient_long: '__transient_short,
+        'b,
+    >(value: Func<'__transient_short, 'b>) -> Func<'__transient_long, 'b> {
+        value
+    }
+};
+const _: () = {
+    #[allow(dead_code)]
+    fn assert_covariant<'__transient_short, '__transient_long: '__transient_short, 'a>(
+        value: Func<'a, '__transient_long>,
+    ) -> Func<'a, '__transient_short> {
+        value
+    }
+};
+struct Mixed<'a> {
+    func: fn(&'a str),
+    value: &'a str,
+}
+#[automatically_derived]
+unsafe impl<'a> ::transient::Transient for Mixed<'a> {
+    type Static = Mixed<'static>;
+    type Transience = ::transient::Inv<'a>;
+}
+struct Interior<'a> {
+    value: Cell<&'a str>,
+}
+#[automatically_derived]
+unsafe impl<'a> ::transient::Transient for Interior<'a> {
+    type Static = Interior<'static>;
+    type Transience = ::transient::Inv<'a>;
+}
+struct TraitObject<'a, 'b> {
+    value: Box<dyn AsRef<&'a str> + 'b>,
+}
+#[automatically_derived]
+unsafe impl<'a, 'b> ::transient::Transient for TraitObject<'a, 'b> {
+    type Static = TraitObject<'static, 'static>;
+    type Transience = (::transient::Inv<'a>, ::transient::Co<'b>);
+}
+const _: () = {
+    #[allow(dead_code)]
+    fn assert_covariant<'__transient_short, '__transient_long: '__transient_short, 'a>(
+        value: TraitObject<'a, '__transient_long>,
+    ) -> TraitObject<'a, '__transient_short> {
+        value
+    }
+};
+struct Markers<'a, 'b> {
+    _co: Co<'a>,
+    _contra: Contra<'b>,
+}
+#[automatically_derived]
+unsafe impl<'a, 'b> ::transient::Transient for Markers<'a, 'b> {
+    type Static = Markers<'static, 'static>;
+    type Transience = (::transient::Co<'a>, ::transient::Contra<'b>);
+}
+const _: () = {
+    #[allow(dead_code)]
+    fn assert_covariant<'__transient_short, '__transient_long: '__transient_short, 'b>(
+        value: Markers<'__transient_long, 'b>,
+    ) -> Markers<'__transient_short, 'b> {
+        value
+    }
+};
+const _: () = {
+    #[allow(dead_code)]
+    fn assert_contravariant<
+        '__transient_short,
+        '__transient_long: '__transient_short,
+        'a,
+    >(value: Markers<'a, '__transient_short>) -> Markers<'a, '__transient_long> {
+        value
+    }
+};
+enum Either<'a, 'b> {
+    Shared(&'a str),
+    Func(fn(&'b str)),
+}
+#[automatically_derived]
+unsafe impl<'a, 'b> ::transient::Transient for Either<'a, 'b> {
+    type Static = Either<'static, 'static>;
+    type Transience = (::transient::Co<'a>, ::transient::Contra<'b>);
+}
+const _: () = {
+    #[allow(dead_code)]
+    fn assert_covariant<'__transient_short, '__transient_long: '__transient_short, 'b>(
+        value: Either<'__transient_long, 'b>,
+    ) -> Either<'__transient_short, 'b> {
+        value
+    }
+};
+const _: () = {
+    #[allow(dead_code)]
+    fn assert_contravariant<
+        '__transient_short,
+        '__transient_long: '__transient_short,
+        'a,
+    >(value: Either<'a, '__transient_short>) -> Either<'a, '__transient_long> {
+        value
+    }
+};
@@ -0,0 +1,47 @@
+//! Verifies that the variance is inferred from the field types as expected
+use std::cell::Cell;
+use transient::{Co, Contra};
+use transient_derive::Transient;
+
+#[derive(Transient)]
+struct SharedRef<'a, T> {
+    value: &'a [Option<Box<T>>],
+}
+
+#[derive(Transient)]
+struct MutRef<'a, 'b> {
+    value: &'a mut &'b str,
+}
+
+#[derive(Transient)]
+struct Func<'a, 'b> {
+    func: fn(&'a str) -> &'b str,
+}
+
+#[derive(Transient)]
+struct Mixed<'a> {
+    func: fn(&'a str),
+    value: &'a str,
+}
+
+#[derive(Transient)]
+struct Interior<'a> {
+    value: Cell<&'a str>,
+}
+
+#[derive(Transient)]
+struct TraitObject<'a, 'b> {
+    value: Box<dyn AsRef<&'a str> + 'b>,
+}
+
+#[derive(Transient)]
+struct Markers<'a, 'b> {
+    _co: Co<'a>,
+    _contra: Contra<'b>,
+}
+
+#[derive(Transient)]
+enum Either<'a, 'b> {
+    Shared(&'a str),
+    Func(fn(&'b str)),
+}
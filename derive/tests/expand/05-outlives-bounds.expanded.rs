@@ -0,0 +1,91 @@
+//! Verifies that outlives bounds and where-clauses are preserved
+use transient_derive::Transient;
+struct View<'long, 'short: 'long> {
+    long: &'long str,
+    short: &'short str,
+}
+#[automatically_derived]
+unsafe impl<'long, 'short: 'long> ::transient::Transient for View<'long, 'short> {
+    type Static = View<'static, 'static>;
+    type Transience = (::transient::Co<'long>, ::transient::Co<'short>);
+}
+const _: () = {
+    #[allow(dead_code)]
+    fn assert_covariant<
+        '__transient_short,
+        '__transient_long: '__transient_short,
+        'short,
+    >(value: View<'__transient_long, 'short>) -> View<'__transient_short, 'short>
+    where
+        'short: '__transient_long,
+        'short: '__transient_short,
+    {
+        value
+    }
+};
+const _: () = {
+    #[allow(dead_code)]
+    fn assert_covariant<
+        '__transient_short,
+        '__transient_long: '__transient_short,
+        'long,
+    >(value: View<'long, '__transient_long>) -> View<'long, '__transient_short>
+    where
+        '__transient_long: 'long,
+        '__transient_short: 'long,
+    {
+        value
+    }
+};
+struct Bounded<'a, 'b, T: 'a>
+where
+    'b: 'a,
+    T: Clone,
+{
+    value: &'a T,
+    other: &'b str,
+}
+#[automatically_derived]
+unsafe impl<'a, 'b, T: 'a + 'static> ::transient::Transient for Bounded<'a, 'b, T>
+where
+    'b: 'a,
+    T: Clone,
+{
+    type Static = Bounded<'static, 'static, T>;
+    type Transience = (::transient::Co<'a>, ::transient::Co<'b>);
+}
+const _: () = {
+    #[allow(dead_code)]
+    fn assert_covariant<
+        '__transient_short,
+        '__transient_long: '__transient_short,
+        'b,
+        T,
+    >(value: Bounded<'__transient_long, 'b, T>) -> Bounded<'__transient_short, 'b, T>
+    where
+        'b: '__transient_long,
+        'b: '__transient_short,
+        T: Clone,
+        T: '__transient_long,
+        T: '__transient_short,
+    {
+        value
+    }
+};
+const _: () = {
+    #[allow(dead_code)]
+    fn assert_covariant<
+        '__transient_short,
+        '__transient_long: '__transient_short,
+        'a,
+        T,
+    >(value: Bounded<'a, '__transient_long, T>) -> Bounded<'a, '__transient_short, T>
+    where
+        '__transient_long: 'a,
+        '__transient_short: 'a,
+        T: Clone,
+        T: 'a,
+    {
+        value
+    }
+};
@@ -0,0 +1,18 @@
+//! Verifies that outlives bounds and where-clauses are preserved
+use transient_derive::Transient;
+
+#[derive(Transient)]
+struct View<'long, 'short: 'long> {
+    long: &'long str,
+    short: &'short str,
+}
+
+#[derive(Transient)]
+struct Bounded<'a, 'b, T: 'a>
+where
+    'b: 'a,
+    T: Clone,
+{
+    value: &'a T,
+    other: &'b str,
+}
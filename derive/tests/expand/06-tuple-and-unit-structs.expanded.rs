@@ -0,0 +1,28 @@
+//! Verifies that tuple structs and unit structs expand as expected
+use transient_derive::Transient;
+struct Wrapper<'a>(&'a str);
+#[automatically_derived]
+unsafe impl<'a> ::transient::Transient for Wrapper<'a> {
+    type Static = Wrapper<'static>;
+    type Transience = ::transient::Co<'a>;
+}
+const _: () = {
+    #[allow(dead_code)]
+    fn assert_covariant<'__transient_short, '__transient_long: '__transient_short>(
+        value: Wrapper<'__transient_long>,
+    ) -> Wrapper<'__transient_short> {
+        value
+    }
+};
+struct Pair<'a, T>(&'a T, #[variance(inv)] usize);
+#[automatically_derived]
+unsafe impl<'a, T: 'static> ::transient::Transient for Pair<'a, T> {
+    type Static = Pair<'static, T>;
+    type Transience = ::transient::Inv<'a>;
+}
+struct Marker;
+#[automatically_derived]
+unsafe impl ::transient::Transient for Marker {
+    type Static = Marker;
+    type Transience = ::transient::Timeless;
+}
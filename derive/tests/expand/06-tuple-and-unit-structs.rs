@@ -0,0 +1,11 @@
+//! Verifies that tuple structs and unit structs expand as expected
+use transient_derive::Transient;
+
+#[derive(Transient)]
+struct Wrapper<'a>(&'a str);
+
+#[derive(Transient)]
+struct Pair<'a, T>(&'a T, #[variance(inv)] usize);
+
+#[derive(Transient)]
+struct Marker;
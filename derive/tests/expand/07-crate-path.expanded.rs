@@ -0,0 +1,31 @@
+//! Verifies that the `crate` option overrides the path to the crate
+use transient_derive::Transient;
+mod my {
+    pub use ::transient;
+}
+#[transient(crate = my::transient)]
+struct LifetimeOnly<'a> {
+    value1: &'a str,
+}
+#[automatically_derived]
+unsafe impl<'a> my::transient::Transient for LifetimeOnly<'a> {
+    type Static = LifetimeOnly<'static>;
+    type Transience = my::transient::Co<'a>;
+}
+const _: () = {
+    #[allow(dead_code)]
+    fn assert_covariant<'__transient_short, '__transient_long: '__transient_short>(
+        value: LifetimeOnly<'__transient_long>,
+    ) -> LifetimeOnly<'__transient_short> {
+        value
+    }
+};
+#[transient(crate = "my::transient")]
+struct NoGenerics {
+    value1: String,
+}
+#[automatically_derived]
+unsafe impl my::transient::Transient for NoGenerics {
+    type Static = NoGenerics;
+    type Transience = my::transient::Timeless;
+}
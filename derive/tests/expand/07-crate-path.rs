@@ -0,0 +1,18 @@
+//! Verifies that the `crate` option overrides the path to the crate
+use transient_derive::Transient;
+
+mod my {
+    pub use ::transient;
+}
+
+#[derive(Transient)]
+#[transient(crate = my::transient)]
+struct LifetimeOnly<'a> {
+    value1: &'a str,
+}
+
+#[derive(Transient)]
+#[transient(crate = "my::transient")]
+struct NoGenerics {
+    value1: String,
+}
@@ -0,0 +1,18 @@
+//! Verifies the expansion when the variance is declared for each lifetime
+use transient_derive::Transient;
+struct Values<'a>(&'a [i32]);
+struct PerLifetime<'a, 'b, 'c> {
+    #[variance('a = unsafe_co, 'b = unsafe_contra)]
+    values: Values<'a>,
+    callback: fn(Values<'b>),
+    other: Values<'c>,
+}
+#[automatically_derived]
+unsafe impl<'a, 'b, 'c> ::transient::Transient for PerLifetime<'a, 'b, 'c> {
+    type Static = PerLifetime<'static, 'static, 'static>;
+    type Transience = (
+        ::transient::Co<'a>,
+        ::transient::Contra<'b>,
+        ::transient::Inv<'c>,
+    );
+}
@@ -0,0 +1,12 @@
+//! Verifies the expansion when the variance is declared for each lifetime
+use transient_derive::Transient;
+
+struct Values<'a>(&'a [i32]);
+
+#[derive(Transient)]
+struct PerLifetime<'a, 'b, 'c> {
+    #[variance('a = unsafe_co, 'b = unsafe_contra)]
+    values: Values<'a>,
+    callback: fn(Values<'b>),
+    other: Values<'c>,
+}
@@ -0,0 +1,69 @@
+//! Verifies the expansion for type parameters marked with `#[transient(bound)]`
+use transient_derive::Transient;
+struct Wrapper<#[transient(bound)] T> {
+    inner: T,
+}
+#[automatically_derived]
+unsafe impl<T: ::transient::Transient> ::transient::Transient for Wrapper<T> {
+    type Static = Wrapper<<T as ::transient::Transient>::Static>;
+    type Transience = <T as ::transient::Transient>::Transience;
+}
+const _: () = {
+    #[allow(dead_code)]
+    fn assert_covariant<'__transient_short, '__transient_long: '__transient_short, T>(
+        value: Wrapper<&'__transient_long T>,
+    ) -> Wrapper<&'__transient_short T> {
+        value
+    }
+};
+struct Labeled<'a, #[transient(bound)] T: Clone, U, const N: usize> {
+    label: &'a str,
+    values: [T; N],
+    extra: U,
+}
+#[automatically_derived]
+unsafe impl<
+    'a,
+    T: Clone + ::transient::Transient,
+    U: 'static,
+    const N: usize,
+> ::transient::Transient for Labeled<'a, T, U, N>
+where
+    <T as ::transient::Transient>::Static: Clone,
+{
+    type Static = Labeled<'static, <T as ::transient::Transient>::Static, U, N>;
+    type Transience = (::transient::Co<'a>, <T as ::transient::Transient>::Transience);
+}
+const _: () = {
+    #[allow(dead_code)]
+    fn assert_covariant<
+        '__transient_short,
+        '__transient_long: '__transient_short,
+        T,
+        U,
+        const N: usize,
+    >(value: Labeled<'__transient_long, T, U, N>) -> Labeled<'__transient_short, T, U, N>
+    where
+        T: Clone,
+    {
+        value
+    }
+};
+const _: () = {
+    #[allow(dead_code)]
+    fn assert_covariant<
+        '__transient_short,
+        '__transient_long: '__transient_short,
+        'a,
+        T,
+        U,
+        const N: usize,
+    >(
+        value: Labeled<'a, &'__transient_long T, U, N>,
+    ) -> Labeled<'a, &'__transient_short T, U, N>
+    where
+        for<'__transient_any> &'__transient_any T: Clone,
+    {
+        value
+    }
+};
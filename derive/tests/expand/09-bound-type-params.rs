@@ -0,0 +1,14 @@
+//! Verifies the expansion for type parameters marked with `#[transient(bound)]`
+use transient_derive::Transient;
+
+#[derive(Transient)]
+struct Wrapper<#[transient(bound)] T> {
+    inner: T,
+}
+
+#[derive(Transient)]
+struct Labeled<'a, #[transient(bound)] T: Clone, U, const N: usize> {
+    label: &'a str,
+    values: [T; N],
+    extra: U,
+}
@@ -0,0 +1,80 @@
+//! Verifies the expansion of the impls generated by `#[transient(references)]`
+use transient_derive::Transient;
+#[transient(references)]
+struct S<'a, 'b: 'a, T> {
+    value: &'a T,
+    func: fn(&'b str),
+}
+#[automatically_derived]
+unsafe impl<'a, 'b: 'a, T: 'static> ::transient::Transient for S<'a, 'b, T> {
+    type Static = S<'static, 'static, T>;
+    type Transience = (::transient::Co<'a>, ::transient::Contra<'b>);
+}
+const _: () = {
+    #[allow(dead_code)]
+    fn assert_covariant<
+        '__transient_short,
+        '__transient_long: '__transient_short,
+        'b,
+        T,
+    >(value: S<'__transient_long, 'b, T>) -> S<'__transient_short, 'b, T>
+    where
+        'b: '__transient_long,
+        'b: '__transient_short,
+    {
+        value
+    }
+};
+const _: () = {
+    #[allow(dead_code)]
+    fn assert_contravariant<
+        '__transient_short,
+        '__transient_long: '__transient_short,
+        'a,
+        T,
+    >(value: S<'a, '__transient_short, T>) -> S<'a, '__transient_long, T>
+    where
+        '__transient_short: 'a,
+        '__transient_long: 'a,
+    {
+        value
+    }
+};
+#[automatically_derived]
+unsafe impl<'__transient_ref, 'a, 'b: 'a, T: 'static> ::transient::Transient
+for &'__transient_ref S<'a, 'b, T> {
+    type Static = &'static S<'static, 'static, T>;
+    type Transience = (
+        ::transient::Co<'__transient_ref>,
+        ::transient::Co<'a>,
+        ::transient::Contra<'b>,
+    );
+}
+#[automatically_derived]
+unsafe impl<'__transient_ref, 'a, 'b: 'a, T: 'static> ::transient::Transient
+for &'__transient_ref mut S<'a, 'b, T> {
+    type Static = &'static mut S<'static, 'static, T>;
+    type Transience = (
+        ::transient::Co<'__transient_ref>,
+        ::transient::Inv<'a>,
+        ::transient::Inv<'b>,
+    );
+}
+#[transient(references)]
+struct NoLifetimes(usize);
+#[automatically_derived]
+unsafe impl ::transient::Transient for NoLifetimes {
+    type Static = NoLifetimes;
+    type Transience = ::transient::Timeless;
+}
+#[automatically_derived]
+unsafe impl<'__transient_ref> ::transient::Transient for &'__transient_ref NoLifetimes {
+    type Static = &'static NoLifetimes;
+    type Transience = ::transient::Co<'__transient_ref>;
+}
+#[automatically_derived]
+unsafe impl<'__transient_ref> ::transient::Transient
+for &'__transient_ref mut NoLifetimes {
+    type Static = &'static mut NoLifetimes;
+    type Transience = ::transient::Co<'__transient_ref>;
+}
@@ -0,0 +1,13 @@
+//! Verifies the expansion of the impls generated by `#[transient(references)]`
+use transient_derive::Transient;
+
+#[derive(Transient)]
+#[transient(references)]
+struct S<'a, 'b: 'a, T> {
+    value: &'a T,
+    func: fn(&'b str),
+}
+
+#[derive(Transient)]
+#[transient(references)]
+struct NoLifetimes(usize);
@@ -0,0 +1,60 @@
+//! Verifies the rewriting of bounds that mention the type's lifetimes
+use transient_derive::Transient;
+pub trait Parse<'a> {}
+struct Parsed<'a, T: Parse<'a> + Clone, #[transient(bound)] U: Parse<'a>>
+where
+    T: Copy + 'a,
+    for<'x> &'x T: PartialEq<&'a str>,
+{
+    input: &'a str,
+    output: (T, U),
+}
+#[automatically_derived]
+unsafe impl<
+    'a,
+    T: Clone + 'static,
+    U: Parse<'a> + ::transient::Transient,
+> ::transient::Transient for Parsed<'a, T, U>
+where
+    T: Copy + 'a,
+    for<'__transient_a> T: Parse<'__transient_a>,
+    <U as ::transient::Transient>::Static: Parse<'static>,
+    for<'x, '__transient_a> &'x T: PartialEq<&'__transient_a str>,
+{
+    type Static = Parsed<'static, T, <U as ::transient::Transient>::Static>;
+    type Transience = (::transient::Co<'a>, <U as ::transient::Transient>::Transience);
+}
+const _: () = {
+    #[allow(dead_code)]
+    fn assert_covariant<'__transient_short, '__transient_long: '__transient_short, T, U>(
+        value: Parsed<'__transient_long, T, U>,
+    ) -> Parsed<'__transient_short, T, U>
+    where
+        for<'x, '__transient_any> &'x T: PartialEq<&'__transient_any str>,
+        for<'__transient_any> T: Parse<'__transient_any>,
+        for<'__transient_any> U: Parse<'__transient_any>,
+        T: Copy + '__transient_long,
+        T: Copy + '__transient_short,
+        T: Clone,
+    {
+        value
+    }
+};
+const _: () = {
+    #[allow(dead_code)]
+    fn assert_covariant<
+        '__transient_short,
+        '__transient_long: '__transient_short,
+        'a,
+        T,
+        U,
+    >(value: Parsed<'a, T, &'__transient_long U>) -> Parsed<'a, T, &'__transient_short U>
+    where
+        for<'__transient_any> &'__transient_any U: Parse<'a>,
+        T: Copy + 'a,
+        for<'x> &'x T: PartialEq<&'a str>,
+        T: Parse<'a> + Clone,
+    {
+        value
+    }
+};
@@ -0,0 +1,14 @@
+//! Verifies the rewriting of bounds that mention the type's lifetimes
+use transient_derive::Transient;
+
+pub trait Parse<'a> {}
+
+#[derive(Transient)]
+struct Parsed<'a, T: Parse<'a> + Clone, #[transient(bound)] U: Parse<'a>>
+where
+    T: Copy + 'a,
+    for<'x> &'x T: PartialEq<&'a str>,
+{
+    input: &'a str,
+    output: (T, U),
+}
@@ -0,0 +1,163 @@
+//! Verifies the expansion for type parameters marked with `#[transient(bound = 'a)]`
+use transient_derive::Transient;
+struct Cache<'a, #[transient(bound = 'a)] T> {
+    items: Vec<T>,
+    name: &'a str,
+}
+#[automatically_derived]
+unsafe impl<'a, T: ::transient::Transient> ::transient::Transient for Cache<'a, T>
+where
+    ::transient::Co<'a>: ::transient::Meet<<T as ::transient::Transient>::Transience>,
+{
+    type Static = Cache<'static, <T as ::transient::Transient>::Static>;
+    type Transience = <::transient::Co<
+        'a,
+    > as ::transient::Meet<<T as ::transient::Transient>::Transience>>::Output;
+}
+const _: () = {
+    #[allow(dead_code)]
+    fn assert_covariant<'__transient_short, '__transient_long: '__transient_short, T>(
+        value: Cache<'__transient_long, T>,
+    ) -> Cache<'__transient_short, T> {
+        value
+    }
+};
+const _: () = {
+    #[allow(dead_code)]
+    fn assert_covariant<
+        '__transient_short,
+        '__transient_long: '__transient_short,
+        'a,
+        T,
+    >(value: Cache<'a, &'__transient_long T>) -> Cache<'a, &'__transient_short T> {
+        value
+    }
+};
+struct Pair<
+    'a,
+    'b,
+    #[transient(bound = 'b)]
+    T,
+    #[transient(bound = 'b)]
+    U,
+    #[transient(bound)]
+    V,
+> {
+    name: &'a str,
+    first: &'b T,
+    second: U,
+    third: V,
+}
+#[automatically_derived]
+unsafe impl<
+    'a,
+    'b,
+    T: ::transient::Transient,
+    U: ::transient::Transient,
+    V: ::transient::Transient,
+> ::transient::Transient for Pair<'a, 'b, T, U, V>
+where
+    ::transient::Co<'b>: ::transient::Meet<<T as ::transient::Transient>::Transience>,
+    <::transient::Co<
+        'b,
+    > as ::transient::Meet<
+        <T as ::transient::Transient>::Transience,
+    >>::Output: ::transient::Meet<<U as ::transient::Transient>::Transience>,
+{
+    type Static = Pair<
+        'static,
+        'static,
+        <T as ::transient::Transient>::Static,
+        <U as ::transient::Transient>::Static,
+        <V as ::transient::Transient>::Static,
+    >;
+    type Transience = (
+        ::transient::Co<'a>,
+        <<::transient::Co<
+            'b,
+        > as ::transient::Meet<
+            <T as ::transient::Transient>::Transience,
+        >>::Output as ::transient::Meet<
+            <U as ::transient::Transient>::Transience,
+        >>::Output,
+        <V as ::transient::Transient>::Transience,
+    );
+}
+const _: () = {
+    #[allow(dead_code)]
+    fn assert_covariant<
+        '__transient_short,
+        '__transient_long: '__transient_short,
+        'b,
+        T,
+        U,
+        V,
+    >(
+        value: Pair<'__transient_long, 'b, T, U, V>,
+    ) -> Pair<'__transient_short, 'b, T, U, V> {
+        value
+    }
+};
+const _: () = {
+    #[allow(dead_code)]
+    fn assert_covariant<
+        '__transient_short,
+        '__transient_long: '__transient_short,
+        'a,
+        T,
+        U,
+        V,
+    >(
+        value: Pair<'a, '__transient_long, T, U, V>,
+    ) -> Pair<'a, '__transient_short, T, U, V> {
+        value
+    }
+};
+const _: () = {
+    #[allow(dead_code)]
+    fn assert_covariant<
+        '__transient_short,
+        '__transient_long: '__transient_short,
+        'a,
+        'b,
+        T,
+        U,
+        V,
+    >(
+        value: Pair<'a, 'b, &'__transient_long T, U, V>,
+    ) -> Pair<'a, 'b, &'__transient_short T, U, V> {
+        value
+    }
+};
+const _: () = {
+    #[allow(dead_code)]
+    fn assert_covariant<
+        '__transient_short,
+        '__transient_long: '__transient_short,
+        'a,
+        'b,
+        T,
+        U,
+        V,
+    >(
+        value: Pair<'a, 'b, T, &'__transient_long U, V>,
+    ) -> Pair<'a, 'b, T, &'__transient_short U, V> {
+        value
+    }
+};
+const _: () = {
+    #[allow(dead_code)]
+    fn assert_covariant<
+        '__transient_short,
+        '__transient_long: '__transient_short,
+        'a,
+        'b,
+        T,
+        U,
+        V,
+    >(
+        value: Pair<'a, 'b, T, U, &'__transient_long V>,
+    ) -> Pair<'a, 'b, T, U, &'__transient_short V> {
+        value
+    }
+};
@@ -0,0 +1,16 @@
+//! Verifies the expansion for type parameters marked with `#[transient(bound = 'a)]`
+use transient_derive::Transient;
+
+#[derive(Transient)]
+struct Cache<'a, #[transient(bound = 'a)] T> {
+    items: Vec<T>,
+    name: &'a str,
+}
+
+#[derive(Transient)]
+struct Pair<'a, 'b, #[transient(bound = 'b)] T, #[transient(bound = 'b)] U, #[transient(bound)] V> {
+    name: &'a str,
+    first: &'b T,
+    second: U,
+    third: V,
+}
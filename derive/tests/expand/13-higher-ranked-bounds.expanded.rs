@@ -0,0 +1,44 @@
+//! Verifies the expansion for higher-ranked bounds mentioning the type's lifetimes
+use transient_derive::Transient;
+struct Parser<'a, F>
+where
+    F: for<'x> Fn(&'x str) -> &'a str,
+{
+    input: &'a str,
+    parse: F,
+}
+#[automatically_derived]
+unsafe impl<'a, F: 'static> ::transient::Transient for Parser<'a, F>
+where
+    F: for<'x> Fn(&'x str) -> &'a str,
+    'a: 'static,
+{
+    type Static = Parser<'static, F>;
+    type Transience = ::transient::Co<'a>;
+}
+struct Matcher<'a, F>
+where
+    F: for<'x> Fn(&'x str, &'a str) -> bool,
+{
+    pattern: &'a str,
+    matches: F,
+}
+#[automatically_derived]
+unsafe impl<'a, F: 'static> ::transient::Transient for Matcher<'a, F>
+where
+    for<'__transient_a, 'x> F: Fn(&'x str, &'__transient_a str) -> bool,
+{
+    type Static = Matcher<'static, F>;
+    type Transience = ::transient::Co<'a>;
+}
+const _: () = {
+    #[allow(dead_code)]
+    fn assert_covariant<'__transient_short, '__transient_long: '__transient_short, F>(
+        value: Matcher<'__transient_long, F>,
+    ) -> Matcher<'__transient_short, F>
+    where
+        for<'__transient_any, 'x> F: Fn(&'x str, &'__transient_any str) -> bool,
+    {
+        value
+    }
+};
@@ -0,0 +1,20 @@
+//! Verifies the expansion for higher-ranked bounds mentioning the type's lifetimes
+use transient_derive::Transient;
+
+#[derive(Transient)]
+struct Parser<'a, F>
+where
+    F: for<'x> Fn(&'x str) -> &'a str,
+{
+    input: &'a str,
+    parse: F,
+}
+
+#[derive(Transient)]
+struct Matcher<'a, F>
+where
+    F: for<'x> Fn(&'x str, &'a str) -> bool,
+{
+    pattern: &'a str,
+    matches: F,
+}
@@ -3,9 +3,42 @@ use transient::Transient;
 
 
 #[derive(Debug, Clone, PartialEq, Eq, Transient)]
-struct S<'a, 'b, T> {
+struct S<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i, T> {
     value1: &'a T,
     value2: &'b T,
+    value3: &'c T,
+    value4: &'d T,
+    value5: &'e T,
+    value6: &'f T,
+    value7: &'g T,
+    value8: &'h T,
+    value9: &'i T,
+}
+
+// each bound type parameter adds an element to the `Transience` tuple
+#[derive(Transient)]
+struct Bound<'a, 'b, 'c, 'd, 'e, 'f, 'g, #[transient(bound)] T, #[transient(bound)] U> {
+    value1: &'a T,
+    value2: &'b U,
+    value3: &'c str,
+    value4: &'d str,
+    value5: &'e str,
+    value6: &'f str,
+    value7: &'g str,
+}
+
+// as does the reference for the reference impls
+#[derive(Transient)]
+#[transient(references)]
+struct Referenced<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h> {
+    value1: &'a str,
+    value2: &'b str,
+    value3: &'c str,
+    value4: &'d str,
+    value5: &'e str,
+    value6: &'f str,
+    value7: &'g str,
+    value8: &'h str,
 }
 
 fn main() {
@@ -0,0 +1,13 @@
+//! Ensure fails when the variants of an enum request conflicting variances
+use transient::Transient;
+
+
+#[derive(Debug, Clone, PartialEq, Eq, Transient)]
+enum E<'a> {
+    Co(#[variance(unsafe_co)] &'a str),
+    Contra(#[variance(unsafe_contra)] fn(&'a str)),
+}
+
+fn main() {
+    // this test should fail to compile
+}
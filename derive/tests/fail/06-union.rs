@@ -0,0 +1,13 @@
+//! Ensure fails when used on a union
+use transient::Transient;
+
+
+#[derive(Transient)]
+union U {
+    int: u32,
+    float: f32,
+}
+
+fn main() {
+    // this test should fail to compile
+}
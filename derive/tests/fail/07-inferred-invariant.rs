@@ -0,0 +1,56 @@
+//! Tests that an inferred invariant lifetime cannot be erased as covariant, and
+//! that types which only look covariant or contravariant (such as local aliases
+//! named like the containers from `std`) are rejected by the coercion check
+use std::cell::Cell;
+use transient::{Any, Co, Transient};
+
+#[derive(Debug, Transient)]
+struct MutRef<'a> {
+    value: &'a mut &'a str,
+}
+
+fn shorten<'short, 'long: 'short>(value: &'short MutRef<'long>) -> &'short dyn Any<Co<'short>> {
+    value
+}
+
+mod shadow {
+    pub type Option<T> = std::cell::Cell<T>;
+}
+
+#[derive(Transient)]
+struct Local<'a> {
+    value: Option<&'a str>,
+}
+
+#[derive(Transient)]
+struct Qualified<'a> {
+    value: shadow::Option<&'a str>,
+}
+
+fn qualified<'short, 'long: 'short>(value: &'short Qualified<'long>) -> &'short dyn Any<Co<'short>> {
+    value
+}
+
+#[derive(Transient)]
+struct Node<'a> {
+    value: &'a str,
+    next: Cell<std::option::Option<Box<Self>>>,
+}
+
+fn node<'short, 'long: 'short>(value: &'short Node<'long>) -> &'short dyn Any<Co<'short>> {
+    value
+}
+
+struct Contra<'a>(Cell<fn(&'a str)>);
+
+#[derive(Transient)]
+struct Marker<'a>(Contra<'a>);
+
+#[derive(Transient)]
+struct Callback<'a>(Option<fn(&'a str)>);
+
+use shadow::Option;
+
+fn main() {
+    // this test should fail to compile
+}
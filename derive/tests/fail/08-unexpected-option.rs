@@ -0,0 +1,12 @@
+//! Tests the behavior when an unsupported `transient` option is provided
+use transient::Transient;
+
+#[derive(Debug, Transient)]
+#[transient(krate = transient)]
+struct S<'a> {
+    value: &'a str,
+}
+
+fn main() {
+    // this test should fail to compile
+}
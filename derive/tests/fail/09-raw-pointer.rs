@@ -0,0 +1,12 @@
+//! Tests the behavior when the variance must be inferred for a raw pointer field
+use transient::Transient;
+
+#[derive(Debug, Transient)]
+struct S<'a> {
+    value: &'a str,
+    ptr: *const u8,
+}
+
+fn main() {
+    // this test should fail to compile
+}
@@ -0,0 +1,12 @@
+//! Tests the behavior when the variance must be inferred for an `UnsafeCell` field
+use std::cell::UnsafeCell;
+use transient::Transient;
+
+#[derive(Debug, Transient)]
+struct S<'a> {
+    value: UnsafeCell<&'a str>,
+}
+
+fn main() {
+    // this test should fail to compile
+}
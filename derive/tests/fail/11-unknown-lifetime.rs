@@ -0,0 +1,12 @@
+//! Ensure fails when the variance is declared for a lifetime the type doesn't have
+use transient::Transient;
+
+#[derive(Debug, Clone, PartialEq, Eq, Transient)]
+struct S<'a> {
+    #[variance('b = unsafe_co)]
+    value: &'a str,
+}
+
+fn main() {
+    // this test should fail to compile
+}
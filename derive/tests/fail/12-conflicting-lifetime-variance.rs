@@ -0,0 +1,14 @@
+//! Ensure fails when a per-lifetime variance conflicts with another declaration
+use transient::Transient;
+
+#[derive(Debug, Clone, PartialEq, Eq, Transient)]
+struct S<'a, 'b> {
+    #[variance(unsafe_co)]
+    value1: &'a str,
+    #[variance('b = unsafe_contra)]
+    value2: fn(&'b str),
+}
+
+fn main() {
+    // this test should fail to compile
+}
@@ -0,0 +1,22 @@
+//! Ensure fails when a bound type parameter is used in an invariant position,
+//! including inside a local alias named like a covariant container from `std`
+use transient::Transient;
+
+#[derive(Debug, Transient)]
+struct S<'a, #[transient(bound)] T> {
+    value: &'a mut T,
+}
+
+mod shadow {
+    pub type Vec<T> = std::cell::Cell<T>;
+}
+use shadow::Vec;
+
+#[derive(Transient)]
+struct Wrapper<#[transient(bound)] T> {
+    values: Vec<T>,
+}
+
+fn main() {
+    // this test should fail to compile
+}
@@ -0,0 +1,11 @@
+//! Ensure fails when a type parameter is given an unrecognized option
+use transient::Transient;
+
+#[derive(Debug, Transient)]
+struct S<#[transient(unbound)] T> {
+    value: T,
+}
+
+fn main() {
+    // this test should fail to compile
+}
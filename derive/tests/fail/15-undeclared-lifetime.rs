@@ -0,0 +1,12 @@
+//! Ensure fails when a field uses a lifetime the type doesn't declare
+use transient::Transient;
+
+#[derive(Debug, Clone, PartialEq, Eq, Transient)]
+struct S<'a> {
+    value: &'a str,
+    other: &'b str,
+}
+
+fn main() {
+    // this test should fail to compile
+}
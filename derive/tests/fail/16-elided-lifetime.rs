@@ -0,0 +1,13 @@
+//! Ensure fails when a field elides a lifetime of a type with several lifetimes
+use transient::Transient;
+
+#[derive(Debug, Clone, PartialEq, Eq, Transient)]
+struct S<'a, 'b> {
+    value: &'a str,
+    other: &'b str,
+    elided: &str,
+}
+
+fn main() {
+    // this test should fail to compile
+}
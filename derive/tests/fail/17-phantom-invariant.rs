@@ -0,0 +1,39 @@
+//! Ensure that a lifetime behind `PhantomData` is inferred as invariant when
+//! the wrapped type is invariant in it, and that types named like `PhantomData`
+//! or the `Co` marker are only trusted by path and checked by coercion
+use std::marker::PhantomData;
+use transient::{Any, Co, Transient};
+
+#[derive(Debug, Transient)]
+struct Branded<'a>(PhantomData<&'a mut &'a ()>);
+
+fn relax<'a>(value: &'a Branded<'a>) -> &'a dyn Any<Co<'a>> {
+    value
+}
+
+mod shadow {
+    pub type PhantomData<T> = std::cell::Cell<T>;
+    pub struct Co<'a>(pub std::cell::Cell<&'a ()>);
+}
+
+#[derive(Transient)]
+struct Qualified<'a>(shadow::PhantomData<&'a str>, shadow::Co<'a>);
+
+fn qualified<'short, 'long: 'short>(value: &'short Qualified<'long>) -> &'short dyn Any<Co<'short>> {
+    value
+}
+
+mod local {
+    use super::shadow::{Co, PhantomData};
+    use transient::Transient;
+
+    #[derive(Transient)]
+    struct Phantom<'a>(PhantomData<&'a str>);
+
+    #[derive(Transient)]
+    struct Marker<'a>(Co<'a>);
+}
+
+fn main() {
+    // this test should fail to compile
+}
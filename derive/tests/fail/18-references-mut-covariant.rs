@@ -0,0 +1,17 @@
+//! Ensure that the lifetimes of a type behind a mutable reference generated by
+//! `#[transient(references)]` are invariant
+use transient::{Any, Co, Transient};
+
+#[derive(Debug, Transient)]
+#[transient(references)]
+struct S<'a> {
+    value: &'a str,
+}
+
+fn relax<'r, 'a>(value: &'r mut S<'a>) -> Box<dyn Any<(Co<'r>, Co<'a>)> + 'r> {
+    Box::new(value)
+}
+
+fn main() {
+    // this test should fail to compile
+}
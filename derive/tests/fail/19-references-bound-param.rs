@@ -0,0 +1,12 @@
+//! Ensure fails when `#[transient(references)]` is combined with a bound type parameter
+use transient::Transient;
+
+#[derive(Debug, Transient)]
+#[transient(references)]
+struct S<#[transient(bound)] T> {
+    value: T,
+}
+
+fn main() {
+    // this test should fail to compile
+}
@@ -0,0 +1,23 @@
+use transient::Transient;
+use std::cell::Cell;
+
+#[derive(Transient)]
+struct MutRef<'a> {
+    #[variance(unsafe_co)]
+    value: &'a mut &'a str,
+}
+
+#[derive(Transient)]
+struct Callback<'a> {
+    #[variance(unsafe_covariant)]
+    func: fn(&'a str) -> usize,
+}
+
+#[derive(Transient)]
+struct Shared<'a, 'b> {
+    #[variance('a = unsafe_co, 'b = unsafe_contra)]
+    first: &'a str,
+    second: Cell<&'b str>,
+}
+
+fn main() {}
@@ -0,0 +1,19 @@
+//! Ensure that a lifetime used only in `PhantomData` is still tracked, so that
+//! an erased value can't be restored with a longer lifetime
+use std::marker::PhantomData;
+use transient::{Any, Co, Downcast, Transient};
+
+#[derive(Debug, Transient)]
+struct Token<'a> {
+    id: u64,
+    _p: PhantomData<&'a ()>,
+}
+
+fn extend<'a>(token: Token<'a>) -> Token<'static> {
+    let erased: Box<dyn Any<Co<'a>> + 'a> = Box::new(token);
+    *erased.downcast::<Token<'static>>().unwrap()
+}
+
+fn main() {
+    // this test should fail to compile
+}
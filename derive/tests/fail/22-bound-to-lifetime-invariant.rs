@@ -0,0 +1,26 @@
+//! Ensure that a type parameter bound to a lifetime with an invariant
+//! transience makes the type invariant in that lifetime
+use transient::{Any, Co, Transient};
+
+#[derive(Debug, Transient)]
+struct Cache<'a, #[transient(bound = 'a)] T> {
+    items: Vec<T>,
+    name: &'a str,
+}
+
+#[derive(Debug, Transient)]
+struct Slot<'a>(&'a mut &'a str);
+
+fn relax<'a>(value: &'a Cache<'a, Slot<'a>>) -> &'a dyn Any<Co<'a>> {
+    value
+}
+
+#[derive(Debug, Transient)]
+struct Unknown<'a, #[transient(bound = 'b)] T> {
+    items: Vec<T>,
+    name: &'a str,
+}
+
+fn main() {
+    // this test should fail to compile
+}
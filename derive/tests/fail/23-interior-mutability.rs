@@ -0,0 +1,31 @@
+//! Ensure that lifetimes inside interior mutability containers are inferred as
+//! invariant, even though the contained reference is covariant
+use std::cell::RefCell;
+use std::sync::Mutex;
+use transient::{Any, Co, Transient};
+
+#[derive(Debug, Transient)]
+struct Shared<'a, T> {
+    value: RefCell<&'a T>,
+}
+
+#[derive(Debug, Transient)]
+struct Locked<'a> {
+    value: Mutex<Option<&'a str>>,
+}
+
+fn shorten<'short, 'long: 'short>(
+    value: &'short Shared<'long, usize>,
+) -> &'short dyn Any<Co<'short>> {
+    value
+}
+
+fn shorten_locked<'short, 'long: 'short>(
+    value: &'short Locked<'long>,
+) -> &'short dyn Any<Co<'short>> {
+    value
+}
+
+fn main() {
+    // this test should fail to compile
+}
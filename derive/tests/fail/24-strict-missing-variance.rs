@@ -0,0 +1,18 @@
+use transient::Transient;
+
+// the variance would be inferred without `strict`
+#[derive(Transient)]
+#[transient(strict)]
+struct Inferred<'a> {
+    value: &'a str,
+}
+
+#[derive(Transient)]
+#[transient(strict)]
+struct Partial<'a, 'b> {
+    #[variance('a = unsafe_co)]
+    first: &'a str,
+    second: &'b str,
+}
+
+fn main() {}
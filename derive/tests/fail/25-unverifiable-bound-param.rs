@@ -0,0 +1,15 @@
+//! Ensure fails when a bound type parameter is only used in an output of a
+//! bound, where its covariance cannot be verified
+use transient::Transient;
+
+#[derive(Transient)]
+struct S<#[transient(bound)] T, I>
+where
+    I: Iterator<Item = T>,
+{
+    iter: I,
+}
+
+fn main() {
+    // this test should fail to compile
+}
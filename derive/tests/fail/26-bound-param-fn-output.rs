@@ -0,0 +1,20 @@
+//! Ensure fails when a bound type parameter is only used in the output of
+//! another parameter's `Fn` bound, instead of failing with an ambiguous bound
+use transient::Transient;
+
+#[derive(Transient)]
+struct S<#[transient(bound)] T, F>
+where
+    F: Fn() -> T,
+{
+    make: F,
+}
+
+#[derive(Transient)]
+struct Inline<#[transient(bound)] T, F: FnOnce() -> T> {
+    make: F,
+}
+
+fn main() {
+    // this test should fail to compile
+}
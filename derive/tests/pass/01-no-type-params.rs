@@ -1,5 +1,5 @@
 //! Tests the behavior when used on structs with no type parameters
-use transient::{Transient, Downcast};
+use transient::{Transient, Downcast, Inv};
 
 #[derive(Debug, Clone, PartialEq, Eq, Transient)]
 struct SS<'a> {
@@ -22,7 +22,7 @@ fn main() {
         assert_eq!(restored, &original);
     }
     { // mut ref
-        let erased = original.erase_mut();
+        let erased = original.transcend_mut::<Inv>();
         assert!(erased.is::<SS>());
         let restored = erased.downcast_mut::<SS>().unwrap();
         assert_eq!(restored.value, &string);
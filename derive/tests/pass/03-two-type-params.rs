@@ -1,5 +1,5 @@
 //! Tests the behavior when used on structs with two type parameters
-use transient::{Transient, Downcast};
+use transient::{Transient, Downcast, Inv};
 
 #[derive(Debug, Clone, PartialEq, Eq, Transient)]
 struct S<'a, T1, T2> {
@@ -26,7 +26,7 @@ fn main() {
     }
     { // mut ref
         let mut cloned = original.clone();
-        let erased = cloned.erase_mut();
+        let erased = cloned.transcend_mut::<Inv>();
         assert!(erased.is::<SS>());
         let restored = erased.downcast_mut::<SS>().unwrap();
         assert_eq!(restored, &mut original);
@@ -0,0 +1,47 @@
+//! Tests the behavior when used on enums with a lifetime parameter
+use transient::{Any, Co, Downcast, Inv, Transient};
+
+#[derive(Debug, Clone, PartialEq, Eq, Transient)]
+enum Node<'a> {
+    Leaf(&'a str),
+    Branch(&'a Node<'a>),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Transient)]
+enum CoNode<'a> {
+    Leaf(#[variance(unsafe_co)] &'a str),
+    Branch(#[variance(unsafe_co)] &'a CoNode<'a>),
+    Empty,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Transient)]
+enum Token {
+    Number(i64),
+    Word { text: String },
+}
+
+fn main() {
+    let string = "qwer".to_string();
+    let leaf = Node::Leaf(&string);
+    let branch = Node::Branch(&leaf);
+    for original in [&leaf, &branch] {
+        let erased: &dyn Any<Inv> = original;
+        assert!(erased.is::<Node>());
+        assert_eq!(erased.downcast_ref::<Node>(), Some(original));
+
+        let erased = Box::new(original.clone()).erase();
+        assert_eq!(erased.downcast::<Node>().unwrap().as_ref(), original);
+    }
+
+    let leaf = CoNode::Leaf(&string);
+    let branch = CoNode::Branch(&leaf);
+    for original in [leaf.clone(), branch, CoNode::Empty] {
+        let erased: Box<dyn Any<Co>> = Box::new(original.clone());
+        assert_eq!(*erased.downcast::<CoNode>().unwrap(), original);
+    }
+
+    for original in [Token::Number(5), Token::Word { text: string.clone() }] {
+        let erased: &dyn Any = &original;
+        assert_eq!(erased.downcast_ref::<Token>(), Some(&original));
+    }
+}
@@ -0,0 +1,60 @@
+//! Tests the behavior when used on structs with multiple lifetime parameters
+use transient::{Transient, Any, Downcast, Co, Inv};
+
+#[derive(Debug, Clone, PartialEq, Eq, Transient)]
+struct TwoRefs<'a, 'b, T> {
+    value1: &'a T,
+    value2: &'b T,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Transient)]
+struct FourRefs<'a, 'b, 'c, 'd> {
+    #[variance(unsafe_co)]
+    value1: &'a str,
+    value2: &'b str,
+    value3: &'c str,
+    value4: &'d str,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Transient)]
+struct EightRefs<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h> {
+    value1: &'a str,
+    value2: &'b str,
+    value3: &'c str,
+    value4: &'d str,
+    value5: &'e str,
+    value6: &'f str,
+    value7: &'g str,
+    value8: &'h str,
+}
+
+// the tuple should match the declaration order of the lifetimes
+fn _check_order<'a, 'b>(value: TwoRefs<'a, 'b, i32>) -> Box<dyn Any<(Inv<'a>, Inv<'b>)> + 'a>
+where
+    'b: 'a,
+{
+    Box::new(value)
+}
+
+fn main() {
+    let (one, two) = (1, 2);
+    let original = TwoRefs { value1: &one, value2: &two };
+    let erased: &dyn Any<(Inv, Inv)> = &original;
+    assert!(erased.is::<TwoRefs<i32>>());
+    let restored = erased.downcast_ref::<TwoRefs<i32>>().unwrap();
+    assert_eq!(restored, &original);
+
+    let (a, b) = ("a".to_string(), "b".to_string());
+    let original = FourRefs { value1: &a, value2: &b, value3: "c", value4: "d" };
+    let erased: Box<dyn Any<(Co, Co, Co, Co)> + '_> = Box::new(original.clone());
+    let restored = erased.downcast::<FourRefs>().unwrap();
+    assert_eq!(*restored, original);
+
+    let original = EightRefs {
+        value1: &a, value2: &b, value3: "c", value4: "d",
+        value5: "e", value6: "f", value7: "g", value8: "h",
+    };
+    let erased: Box<dyn Any<(Co, Co, Co, Co, Co, Co, Co, Co)> + '_> = Box::new(original.clone());
+    let restored = erased.downcast::<EightRefs>().unwrap();
+    assert_eq!(*restored, original);
+}
@@ -0,0 +1,45 @@
+//! Tests that the variance is inferred from the fields when no attribute is given
+use transient::{Transient, Any, Downcast, Co, Contra, Inv};
+
+#[derive(Debug, Clone, PartialEq, Eq, Transient)]
+struct SharedRef<'a> {
+    value: &'a str,
+}
+
+#[derive(Debug, Transient)]
+struct FuncWrap<'a> {
+    func: fn(&'a str) -> usize,
+}
+
+#[derive(Debug, Transient)]
+struct MutRef<'a> {
+    value: &'a mut String,
+}
+
+// the inferred covariance allows the lifetime to be shortened
+fn shorten<'short, 'long: 'short>(value: &'short SharedRef<'long>) -> &'short dyn Any<Co<'short>> {
+    value
+}
+
+// the inferred contravariance allows the lifetime to be lengthened
+fn lengthen<'short, 'long: 'short>(value: &'short FuncWrap<'short>) -> &'short dyn Any<Contra<'long>> {
+    value
+}
+
+fn main() {
+    let string = "qwer".to_string();
+    let original = SharedRef { value: &string };
+    let erased = shorten(&original);
+    assert_eq!(erased.downcast_ref::<SharedRef>(), Some(&original));
+
+    let original = FuncWrap { func: str::len };
+    let erased = lengthen(&original);
+    let restored = erased.downcast_ref::<FuncWrap>().unwrap();
+    assert_eq!((restored.func)("four"), 4);
+
+    let mut string = "qwer".to_string();
+    let mut original = MutRef { value: &mut string };
+    let erased: &mut dyn Any<Inv> = &mut original;
+    erased.downcast_mut::<MutRef>().unwrap().value.push('t');
+    assert_eq!(string, "qwert");
+}
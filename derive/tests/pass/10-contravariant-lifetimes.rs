@@ -0,0 +1,47 @@
+//! Tests contravariance for structs with multiple lifetime parameters
+use transient::{Transient, Any, Downcast, Contra, Inv};
+
+// every lifetime is declared contravariant by the attribute
+#[derive(Debug, Transient)]
+struct TwoFuncs<'a, 'b> {
+    #[variance(unsafe_contra)]
+    func_a: fn(&'a str) -> usize,
+    func_b: fn(&'b str) -> usize,
+}
+
+// `'a` is inferred as contravariant while `'b` is left invariant
+#[derive(Debug, Transient)]
+struct FuncAndMut<'a, 'b> {
+    func: fn(&'a str) -> usize,
+    value: &'b mut &'b str,
+}
+
+// the contravariant lifetimes can be lengthened during the coercion
+fn lengthen<'b, 'short, 'long: 'short>(
+    value: &'b TwoFuncs<'short, 'short>,
+) -> &'b dyn Any<(Contra<'long>, Contra<'long>)> {
+    value
+}
+
+// the contravariant lifetime can be lengthened while the other is held fixed
+fn lengthen_first<'b, 'short, 'long: 'short>(
+    value: &'b FuncAndMut<'short, 'short>,
+) -> &'b dyn Any<(Contra<'long>, Inv<'short>)> {
+    value
+}
+
+fn main() {
+    let temp_string = "temp_str".to_string();
+
+    let original = TwoFuncs { func_a: str::len, func_b: str::len };
+    let erased = lengthen(&original);
+    let restored = erased.downcast_ref::<TwoFuncs>().unwrap();
+    assert_eq!((restored.func_a)(&temp_string), 8);
+    assert_eq!((restored.func_b)("static"), 6);
+
+    let mut value: &str = &temp_string;
+    let original = FuncAndMut { func: str::len, value: &mut value };
+    let erased = lengthen_first(&original);
+    let restored = erased.downcast_ref::<FuncAndMut>().unwrap();
+    assert_eq!((restored.func)(restored.value), 8);
+}
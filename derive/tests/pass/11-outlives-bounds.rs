@@ -0,0 +1,31 @@
+//! Tests the behavior when used on structs with outlives bounds
+use transient::{Transient, Any, Downcast, Co, Inv};
+
+#[derive(Debug, Clone, PartialEq, Eq, Transient)]
+struct View<'long, 'short: 'long> {
+    long: &'long str,
+    short: &'short str,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Transient)]
+struct Bounded<'a, 'b, T: 'a>
+where
+    'b: 'a,
+    T: Clone,
+{
+    value: &'a T,
+    other: &'b str,
+}
+
+fn main() {
+    let (long, short) = ("long".to_string(), "short".to_string());
+    let original = View { long: &long, short: &short };
+    let erased: &dyn Any<(Co, Co)> = &original;
+    let restored = erased.downcast_ref::<View>().unwrap();
+    assert_eq!(restored, &original);
+
+    let original = Bounded { value: &5, other: &short };
+    let erased: Box<dyn Any<(Inv, Inv)> + '_> = Box::new(original.clone());
+    let restored = erased.downcast::<Bounded<i32>>().unwrap();
+    assert_eq!(*restored, original);
+}
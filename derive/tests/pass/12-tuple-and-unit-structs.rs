@@ -0,0 +1,30 @@
+//! Tests the behavior when used on tuple structs and unit structs
+use transient::{Transient, Any, Downcast, Co};
+
+#[derive(Debug, Clone, PartialEq, Eq, Transient)]
+struct Wrapper<'a>(&'a str);
+
+#[derive(Debug, Clone, PartialEq, Eq, Transient)]
+struct Pair<'a, T>(&'a T, #[variance(inv)] usize);
+
+#[derive(Debug, Clone, PartialEq, Eq, Transient)]
+struct Marker;
+
+fn main() {
+    let string = "qwer".to_string();
+    let original = Wrapper(&string);
+    let erased: Box<dyn Any<Co> + '_> = Box::new(original.clone());
+    let restored = erased.downcast::<Wrapper>().unwrap();
+    assert_eq!(*restored, original);
+
+    let original = Pair(&string, 5);
+    let erased = original.erase_ref();
+    let restored = erased.downcast_ref::<Pair<String>>().unwrap();
+    assert_eq!(restored, &original);
+
+    let original = Marker;
+    let erased: Box<dyn Any> = Box::new(original.clone());
+    assert!(erased.is::<Marker>());
+    let restored = erased.downcast::<Marker>().unwrap();
+    assert_eq!(*restored, original);
+}
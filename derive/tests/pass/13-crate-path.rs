@@ -0,0 +1,29 @@
+//! Tests the `#[transient(crate = ...)]` attribute for renaming the crate path
+use transient::{Any, Downcast, Co};
+
+mod reexport {
+    pub use transient as renamed;
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, transient::Transient)]
+#[transient(crate = reexport::renamed)]
+struct WithPath<'a> {
+    value: &'a str,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, transient::Transient)]
+#[transient(crate = "reexport::renamed")]
+struct WithString<T> {
+    value: T,
+}
+
+fn main() {
+    let string = "qwer".to_string();
+    let original = WithPath { value: &string };
+    let erased: &dyn Any<Co> = &original;
+    assert_eq!(erased.downcast_ref::<WithPath>(), Some(&original));
+
+    let original = WithString { value: 5 };
+    let erased: &dyn Any = &original;
+    assert_eq!(erased.downcast_ref::<WithString<i32>>(), Some(&original));
+}
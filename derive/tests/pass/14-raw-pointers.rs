@@ -0,0 +1,40 @@
+//! Tests raw pointer and `UnsafeCell` fields with an explicit variance
+use std::cell::UnsafeCell;
+use std::marker::PhantomData;
+use transient::{Transient, Any, Downcast, Inv};
+
+#[derive(Debug, Transient)]
+struct Ptr<'a> {
+    #[variance(inv)]
+    ptr: *const u8,
+    _marker: PhantomData<&'a [u8]>,
+}
+
+#[derive(Debug, Transient)]
+struct Cell<'a> {
+    #[variance(invariant)]
+    value: UnsafeCell<&'a str>,
+}
+
+// `'static` types have no variance to infer, so raw pointers are allowed
+#[derive(Debug, Transient)]
+struct StaticPtr {
+    ptr: *mut u8,
+}
+
+fn main() {
+    let bytes = vec![1u8, 2, 3];
+    let original = Ptr { ptr: bytes.as_ptr(), _marker: PhantomData };
+    let erased: &dyn Any<Inv> = &original;
+    assert_eq!(erased.downcast_ref::<Ptr>().unwrap().ptr, bytes.as_ptr());
+
+    let string = "qwer".to_string();
+    let original = Cell { value: UnsafeCell::new(&string) };
+    let erased: Box<dyn Any<Inv> + '_> = Box::new(original);
+    let restored = erased.downcast::<Cell>().unwrap();
+    assert_eq!(restored.value.into_inner(), "qwer");
+
+    let original = StaticPtr { ptr: std::ptr::null_mut() };
+    let erased: &dyn Any = &original;
+    assert!(erased.downcast_ref::<StaticPtr>().unwrap().ptr.is_null());
+}
@@ -0,0 +1,52 @@
+//! Tests declaring the variance of each lifetime separately
+use transient::{Transient, Any, Downcast, Co, Contra, Inv};
+
+#[derive(Clone, PartialEq, Eq)]
+struct Values<'a>(&'a [i32]);
+
+#[derive(Clone, PartialEq, Eq)]
+struct Callback<'a>(fn(&'a str) -> usize);
+
+// each lifetime is declared on the field that uses it, and any lifetime that
+// isn't mentioned (`'c` here) is invariant
+#[derive(Clone, PartialEq, Eq, Transient)]
+struct M<'s, 'l, 'c> {
+    #[variance('s = unsafe_co)]
+    values: Values<'s>,
+    #[variance('l = unsafe_contra)]
+    callback: Callback<'l>,
+    other: Values<'c>,
+}
+
+// several lifetimes can be declared in one attribute, and repeating the same
+// declaration is allowed
+#[derive(Clone, PartialEq, Eq, Transient)]
+struct Pair<'a, 'b> {
+    #[variance('a = unsafe_covariant, 'b = unsafe_contra)]
+    values: Values<'a>,
+    #[variance('b = unsafe_contravariant)]
+    callback: Callback<'b>,
+}
+
+fn shorten_and_lengthen<'x, 'short, 'long: 'short>(
+    value: &'x M<'long, 'short, 'short>,
+) -> &'x dyn Any<(Co<'short>, Contra<'long>, Inv<'short>)> {
+    value
+}
+
+fn main() {
+    let values = [1, 2, 3];
+    let original = M {
+        values: Values(&values),
+        callback: Callback(str::len),
+        other: Values(&values),
+    };
+    let erased = shorten_and_lengthen(&original);
+    let restored = erased.downcast_ref::<M>().unwrap();
+    assert_eq!(restored.values.0, &[1, 2, 3]);
+    assert_eq!((restored.callback.0)("four"), 4);
+
+    let original = Pair { values: Values(&values), callback: Callback(str::len) };
+    let erased: Box<dyn Any<(Co, Contra)> + '_> = Box::new(original.clone());
+    assert!(*erased.downcast::<Pair>().unwrap() == original);
+}
@@ -0,0 +1,63 @@
+//! Tests type parameters marked with `#[transient(bound)]`, which may borrow
+use transient::{Transient, Any, Downcast, Co, Inv};
+
+#[derive(Debug, Clone, PartialEq, Eq, Transient)]
+struct Wrapper<#[transient(bound)] T> {
+    inner: T,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Transient)]
+struct Labeled<'a, #[transient(bound)] T, U> {
+    label: &'a str,
+    values: Vec<T>,
+    extra: U,
+}
+
+// `T` is also an input of the bound, so it can be given a `Static` copy
+#[derive(Transient)]
+struct Mapper<#[transient(bound)] T, F>
+where
+    F: Fn(T) -> T,
+{
+    value: T,
+    map: F,
+}
+
+fn trim(value: &str) -> &str {
+    value.trim()
+}
+
+// the transience of `T` is appended after that of the lifetimes
+fn erase<'a, 'b>(
+    value: Labeled<'a, &'b str, usize>,
+) -> Box<dyn Any<(Co<'a>, Co<'b>)> + 'a>
+where
+    'b: 'a,
+{
+    Box::new(value)
+}
+
+fn main() {
+    let string = "qwer".to_string();
+    let original = Wrapper { inner: string.as_str() };
+    let erased: &dyn Any<Co> = &original;
+    assert_eq!(erased.downcast_ref::<Wrapper<&str>>().unwrap().inner, "qwer");
+
+    // a 'static parameter has a `Timeless` transience, so it can be nested too
+    let original = Wrapper { inner: 5_usize };
+    let erased: &dyn Any = &original;
+    assert!(erased.is::<Wrapper<usize>>());
+
+    let original = Labeled { label: &string, values: vec![string.as_str()], extra: 5_usize };
+    let erased = erase(original.clone());
+    let restored = erased.downcast::<Labeled<&str, usize>>().unwrap();
+    assert_eq!(*restored, original);
+
+    let erased: Box<dyn Any<Inv> + '_> = Box::new(original);
+    assert!(erased.is::<Labeled<&str, usize>>());
+
+    let original = Mapper { value: " qwer ", map: trim as fn(&str) -> &str };
+    let erased: &dyn Any<Co> = &original;
+    let mapper = erased.downcast_ref::<Mapper<&str, fn(&str) -> &str>>().unwrap();
+    assert_eq!((mapper.map)(mapper.value), "qwer");
+}
@@ -0,0 +1,29 @@
+//! Tests that lifetimes which are bound within a field's type (rather than by
+//! the deriving type) are ignored by the variance inference.
+use transient::{Any, Co, Downcast, Transient};
+
+#[derive(Transient)]
+struct S<'a> {
+    value: &'a str,
+    elided: fn(&str) -> &str,
+    bound: for<'x> fn(&'x str) -> &'x str,
+    boxed: Box<dyn for<'x> Fn(&'x str) -> usize + 'a>,
+    sugar: Box<dyn Fn(&str) -> &str + 'a>,
+    constant: &'static str,
+}
+
+fn main() {
+    let string = "qwer".to_string();
+    let original = S {
+        value: &string,
+        elided: |s| s,
+        bound: |s| s,
+        boxed: Box::new(|s| s.len()),
+        sugar: Box::new(|s| s),
+        constant: "asdf",
+    };
+    let erased: &dyn Any<Co> = &original;
+    let restored = erased.downcast_ref::<S>().unwrap();
+    assert_eq!((restored.bound)(restored.value), "qwer");
+    assert_eq!((restored.boxed)(restored.constant), 4);
+}
@@ -0,0 +1,56 @@
+//! Tests that `PhantomData<X>` fields are inferred exactly as if they were `X`
+use std::marker::PhantomData;
+use transient::{Transient, Any, Downcast, Co, Contra, Inv};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Transient)]
+struct Shared<'a>(PhantomData<&'a ()>);
+
+// the lifetime only appears in the `PhantomData`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Transient)]
+struct Token<'a> {
+    id: u64,
+    _p: PhantomData<&'a ()>,
+}
+
+// paths into `std` and this crate are recognized as well
+#[derive(Debug, Transient)]
+struct Qualified<'a>(std::marker::PhantomData<&'a ()>, Option<transient::Co<'a>>);
+
+#[derive(Debug, Transient)]
+struct Phase<'a>(PhantomData<&'a mut ()>);
+
+#[derive(Debug, Transient)]
+struct Callback<'a>(PhantomData<fn(&'a str)>);
+
+#[derive(Debug, Transient)]
+struct Branded<'a>(PhantomData<&'a mut &'a ()>);
+
+fn shorten<'short, 'long: 'short>(value: &'short Shared<'long>) -> &'short dyn Any<Co<'short>> {
+    value
+}
+
+fn lengthen<'short, 'long: 'short>(value: &'short Callback<'short>) -> &'short dyn Any<Contra<'long>> {
+    value
+}
+
+fn main() {
+    let original = Shared(PhantomData);
+    assert_eq!(shorten(&original).downcast_ref::<Shared>(), Some(&original));
+
+    let token = Token { id: 7, _p: PhantomData };
+    let erased: Box<dyn Any<Co>> = Box::new(token);
+    assert_eq!(*erased.downcast::<Token>().unwrap(), token);
+
+    let erased: &dyn Any<Co> = &Qualified(PhantomData, None);
+    assert!(erased.is::<Qualified>());
+
+    // `&'a mut ()` is still covariant in `'a`
+    let erased: &dyn Any<Co> = &Phase(PhantomData);
+    assert!(erased.is::<Phase>());
+
+    let original = Callback(PhantomData);
+    assert!(lengthen(&original).is::<Callback>());
+
+    let erased: &dyn Any<Inv> = &Branded(PhantomData);
+    assert!(erased.is::<Branded>());
+}
@@ -0,0 +1,34 @@
+//! Tests the impls for references generated by `#[transient(references)]`
+use transient::{Transient, Any, Downcast, Co, Inv};
+
+#[derive(Debug, Clone, PartialEq, Eq, Transient)]
+#[transient(references)]
+struct S<'a> {
+    value: &'a str,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Transient)]
+#[transient(references)]
+struct NoLifetimes(usize);
+
+fn shorten<'short, 'long: 'short>(
+    value: &'short S<'long>,
+) -> Box<dyn Any<(Co<'short>, Co<'short>)> + 'short> {
+    Box::new(value)
+}
+
+fn main() {
+    let string = "qwer".to_string();
+    let original = S { value: &string };
+    let erased = shorten(&original);
+    assert_eq!(*erased.downcast::<&S>().unwrap(), &original);
+
+    let mut original = S { value: &string };
+    let erased: Box<dyn Any<(Co, Inv)> + '_> = Box::new(&mut original);
+    erased.downcast::<&mut S>().unwrap().value = "asdf";
+    assert_eq!(original.value, "asdf");
+
+    let original = NoLifetimes(5);
+    let erased: &dyn Any<Co> = &&original;
+    assert_eq!(erased.downcast_ref::<&NoLifetimes>(), Some(&&original));
+}
@@ -0,0 +1,63 @@
+//! Tests bounds on the type parameters that mention the type's lifetimes
+use std::borrow::Borrow;
+use transient::{Transient, Any, Downcast, Co, Inv};
+
+pub trait Parse<'a> {
+    fn parse(input: &'a str) -> Self;
+}
+
+impl Parse<'_> for usize {
+    fn parse(input: &str) -> Self {
+        input.len()
+    }
+}
+
+impl<'a> Parse<'a> for &'a str {
+    fn parse(input: &'a str) -> Self {
+        input
+    }
+}
+
+#[derive(Debug, Transient)]
+struct Parsed<'a, T: Parse<'a>> {
+    input: &'a str,
+    output: T,
+}
+
+#[derive(Debug, Transient)]
+struct Borrowed<'a, T>
+where
+    T: Borrow<str> + 'a,
+    for<'x> &'x T: PartialEq<&'x str>,
+    Vec<T>: Parse<'a>,
+{
+    value: &'a T,
+}
+
+#[derive(Debug, Transient)]
+struct ParsedRef<'a, #[transient(bound)] T: Parse<'a>> {
+    input: &'a str,
+    output: T,
+}
+
+impl<T> Parse<'_> for Vec<T> {
+    fn parse(_: &str) -> Self {
+        Vec::new()
+    }
+}
+
+fn main() {
+    let string = "qwer".to_string();
+    let original = Parsed { input: &string, output: usize::parse(&string) };
+    let erased: &dyn Any<Co> = &original;
+    assert_eq!(erased.downcast_ref::<Parsed<usize>>().unwrap().output, 4);
+
+    let original = ParsedRef { input: &string, output: <&str>::parse(&string) };
+    let erased: &dyn Any<(Co, Co)> = &original;
+    assert_eq!(erased.downcast_ref::<ParsedRef<&str>>().unwrap().output, "qwer");
+
+    let value = "asdf".to_string();
+    let original = Borrowed { value: &value };
+    let erased: &dyn Any<Inv> = &original;
+    assert_eq!(erased.downcast_ref::<Borrowed<String>>().unwrap().value, "asdf");
+}
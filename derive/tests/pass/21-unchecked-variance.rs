@@ -0,0 +1,43 @@
+//! Tests that declared variances are only checked against certain uses
+use std::cell::Cell;
+use transient::{Any, Co, Contra, Downcast, Transient};
+
+struct Wrapper<'a>(Cell<&'a str>);
+
+// the variance of `Wrapper` is unknown to the derive, so it isn't checked
+#[derive(Transient)]
+struct Unknown<'a> {
+    #[variance(unsafe_co)]
+    wrapper: &'a Wrapper<'a>,
+}
+
+// a const raw pointer is covariant in its pointee
+#[derive(Transient)]
+struct ConstPtr<'a> {
+    #[variance(unsafe_co)]
+    ptr: *const &'a str,
+}
+
+// `unchecked` skips the check for every declared variance
+#[derive(Transient)]
+struct Unchecked<'a, 'b> {
+    #[variance('a = unsafe_contra, unchecked)]
+    value: &'a mut &'a str,
+    #[variance('b = unsafe_co)]
+    cell: Cell<&'b str>,
+}
+
+fn main() {
+    let wrapper = Wrapper(Cell::new("value"));
+    let erased: &dyn Any<Co> = &Unknown { wrapper: &wrapper };
+    assert!(erased.is::<Unknown>());
+
+    let value = "value";
+    let erased: &dyn Any<Co> = &ConstPtr { ptr: &value };
+    assert!(erased.is::<ConstPtr>());
+
+    let mut value = "value";
+    let checked = Unchecked { value: &mut value, cell: Cell::new("cell") };
+    let erased: &dyn Any<(Contra, Co)> = &checked;
+    assert!(erased.is::<Unchecked>());
+}
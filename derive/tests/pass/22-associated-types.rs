@@ -0,0 +1,31 @@
+//! Tests deriving for types with associated-type projections in their fields
+use transient::{Any, Co, Downcast, Inv, Transient};
+
+#[derive(Debug, Transient)]
+struct Peeked<'a, T: Iterator> {
+    iter: T,
+    item: &'a T::Item,
+}
+
+#[derive(Transient)]
+struct Projected<'a, T>
+where
+    T: IntoIterator,
+    <T as IntoIterator>::Item: Clone,
+{
+    items: &'a [<T as IntoIterator>::Item],
+}
+
+fn main() {
+    let first = 1;
+    let original = Peeked { iter: vec![2, 3].into_iter(), item: &first };
+    let erased: Box<dyn Any<Co>> = Box::new(original);
+    let restored = erased.downcast::<Peeked<std::vec::IntoIter<i32>>>().unwrap();
+    assert_eq!(restored.item, &1);
+    assert_eq!(restored.iter.collect::<Vec<_>>(), [2, 3]);
+
+    let items = [1, 2, 3];
+    let original: Projected<Vec<i32>> = Projected { items: &items };
+    let erased: &dyn Any<Inv> = &original;
+    assert_eq!(erased.downcast_ref::<Projected<Vec<i32>>>().unwrap().items, &items);
+}
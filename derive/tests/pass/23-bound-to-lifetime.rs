@@ -0,0 +1,37 @@
+//! Tests type parameters marked with `#[transient(bound = 'a)]`, whose
+//! transience is met with the variance of `'a`
+use transient::{Transient, Any, Downcast, Co, Inv};
+
+#[derive(Debug, Clone, PartialEq, Eq, Transient)]
+struct Cache<'a, #[transient(bound = 'a)] T> {
+    items: Vec<T>,
+    name: &'a str,
+}
+
+#[derive(Debug, PartialEq, Eq, Transient)]
+struct Slot<'a>(&'a mut &'a str);
+
+// a covariant `T` leaves the type covariant
+fn shorten<'short, 'long: 'short>(
+    value: Cache<'long, &'long str>,
+) -> Box<dyn Any<Co<'short>> + 'short> {
+    Box::new(value)
+}
+
+fn main() {
+    let (a, b) = ("a".to_string(), "b".to_string());
+    let original = Cache { items: vec![a.as_str(), b.as_str()], name: "strs" };
+    let restored = shorten(original.clone()).downcast::<Cache<&str>>().unwrap();
+    assert_eq!(*restored, original);
+
+    // a `Timeless` `T` is ignored
+    let original = Cache { items: vec![1, 2, 3], name: &a };
+    let erased: &dyn Any<Co> = &original;
+    assert_eq!(erased.downcast_ref::<Cache<i32>>(), Some(&original));
+
+    // an invariant `T` makes the type invariant
+    let mut value = "value";
+    let original = Cache { items: vec![Slot(&mut value)], name: "slots" };
+    let erased: &dyn Any<Inv> = &original;
+    assert_eq!(erased.downcast_ref::<Cache<Slot>>().unwrap().name, "slots");
+}
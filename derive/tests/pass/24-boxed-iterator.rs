@@ -0,0 +1,18 @@
+//! Tests deriving for a type that owns a boxed iterator borrowing its lifetime
+use transient::{Transient, Any, Downcast, Inv};
+
+// the `Static` type only replaces the lifetime argument of the struct, and
+// `'a` is invariant since it appears in an associated type binding of the
+// trait object
+#[derive(Transient)]
+struct Numbers<'a> {
+    iter: Box<dyn Iterator<Item = &'a u32> + 'a>,
+}
+
+fn main() {
+    let values = vec![1, 2, 3];
+    let original = Numbers { iter: Box::new(values.iter()) };
+    let erased: Box<dyn Any<Inv>> = Box::new(original);
+    let restored = erased.downcast::<Numbers>().ok().unwrap();
+    assert_eq!(restored.iter.sum::<u32>(), 6);
+}
@@ -0,0 +1,44 @@
+//! Tests bounds with higher-ranked lifetimes that also mention the type's lifetimes
+use transient::{Transient, Any, Downcast, Inv};
+
+// `'a` only appears in the output of the bound, so the impl only applies when
+// it is `'static` (which is the only way for a `F: 'static` to satisfy it)
+#[derive(Transient)]
+struct Parser<'a, F>
+where
+    F: for<'x> Fn(&'x str) -> &'a str,
+{
+    input: &'a str,
+    parse: F,
+}
+
+// `'a` appears in an input, so the bound is generalized over it alongside `'x`
+#[derive(Transient)]
+struct Matcher<'a, F>
+where
+    F: for<'x> Fn(&'x str, &'a str) -> bool,
+{
+    pattern: &'a str,
+    matches: F,
+}
+
+fn pick(_: &str) -> &'static str {
+    "picked"
+}
+
+fn contains(input: &str, pattern: &str) -> bool {
+    input.contains(pattern)
+}
+
+fn main() {
+    let original = Parser { input: "input", parse: pick as fn(&str) -> &'static str };
+    let erased: &dyn Any<Inv> = &original;
+    let restored = erased.downcast_ref::<Parser<fn(&str) -> &'static str>>().unwrap();
+    assert_eq!((restored.parse)(restored.input), "picked");
+
+    let pattern = "in".to_string();
+    let original = Matcher { pattern: &pattern, matches: contains as fn(&str, &str) -> bool };
+    let erased: Box<dyn Any<Inv> + '_> = Box::new(original);
+    let restored = erased.downcast::<Matcher<fn(&str, &str) -> bool>>().ok().unwrap();
+    assert!((restored.matches)("input", restored.pattern));
+}
@@ -0,0 +1,36 @@
+//! Tests types marked with `#[transient(strict)]`, which must declare the
+//! variance of every lifetime
+use transient::{Transient, Any, Downcast, Co, Inv};
+
+#[derive(Debug, PartialEq, Transient)]
+#[transient(strict)]
+struct Declared<'a, 'b> {
+    #[variance('a = unsafe_co, 'b = inv)]
+    first: &'a str,
+    second: &'b str,
+}
+
+#[derive(Debug, PartialEq, Transient)]
+#[transient(strict)]
+struct All<'a> {
+    #[variance(inv)]
+    value: &'a str,
+}
+
+// types without lifetimes don't need any declarations
+#[derive(Debug, PartialEq, Transient)]
+#[transient(strict)]
+struct Unit;
+
+fn main() {
+    let original = Declared { first: "first", second: "second" };
+    let erased: &dyn Any<(Co, Inv)> = &original;
+    assert_eq!(erased.downcast_ref::<Declared>(), Some(&original));
+
+    let original = All { value: "value" };
+    let erased: &dyn Any<Inv> = &original;
+    assert_eq!(erased.downcast_ref::<All>(), Some(&original));
+
+    let erased: &dyn Any = &Unit;
+    assert!(erased.is::<Unit>());
+}
@@ -0,0 +1,33 @@
+//! Tests that the generated impls resolve the `transient` crate even when a
+//! local module of the same name is in scope
+use ::transient::{Any, Co, Downcast, Transient};
+
+#[allow(dead_code)]
+mod transient {
+    pub trait Transient {}
+}
+
+#[derive(Debug, PartialEq, Transient)]
+struct Shadowed<'a> {
+    value: &'a str,
+}
+
+mod inner {
+    use super::transient;
+
+    #[derive(::transient::Transient)]
+    pub struct Nested {
+        pub value: usize,
+    }
+    impl transient::Transient for Nested {}
+}
+
+fn main() {
+    let string = "qwer".to_string();
+    let original = Shadowed { value: &string };
+    let erased: &dyn Any<Co> = &original;
+    assert_eq!(erased.downcast_ref::<Shadowed>(), Some(&original));
+
+    let erased: Box<dyn Any> = Box::new(inner::Nested { value: 5 });
+    assert_eq!(erased.downcast::<inner::Nested>().unwrap().value, 5);
+}
@@ -2,13 +2,17 @@
 //! [`Any`] and [`TypeId`] that support non-`'static` types alongside
 //! re-exports of [`type_name`] and [`type_name_of_val`].
 use crate::{
-    transience::{CanRecoverFrom, CanTranscendTo, Transience},
+    transience::{CanRecoverFrom, CanTranscendTo, Invariant, Transience},
     transient::Transient,
 };
+#[cfg(feature = "alloc")]
+use alloc::{boxed::Box, rc::Rc, sync::Arc};
+#[cfg(feature = "alloc")]
+use core::pin::Pin;
 
 /// Re-export from the [`std::any`] module.
 ///
-pub use std::any::{type_name, type_name_of_val};
+pub use core::any::{type_name, type_name_of_val};
 
 ///////////////////////////////////////////////////////////////////////////////
 // `Any` trait
@@ -65,6 +69,56 @@ pub trait Any<R: Transience = ()> {
     /// the [`TypeId::of_val`] function (e.g. `TypeId::of_val(&value)`) or the
     /// `Transient::static_type_id` method (e.g. `value.static_type_id()`)
     fn type_id(&self) -> TypeId;
+
+    /// Returns the name of the concrete type of `self` as a string slice, which
+    /// is typically used for debugging an erased `dyn Any` trait object.
+    ///
+    /// The returned string is the same as would be given by [`type_name`] for
+    /// the original type, and is subject to the same caveats (in particular,
+    /// it is not guaranteed to be unique or stable across compiler versions).
+    ///
+    /// # Examples
+    /// ```
+    /// use transient::{Any, Co};
+    ///
+    /// let value = 5_i32;
+    /// let erased: &dyn Any<Co> = &&value;
+    /// assert_eq!(erased.type_name(), "&i32");
+    /// ```
+    fn type_name(&self) -> &'static str;
+
+    /// Views the erased value as a [`std::any::Any`] trait object, for use with
+    /// code that is not aware of this crate.
+    ///
+    /// For the common case of a `dyn Any<()>` (which can only contain `'static`
+    /// types), the safe `as_std_any` method defined on the trait object should
+    /// be used instead.
+    ///
+    /// # Safety
+    /// The returned trait object contains the value as its `T::Static` type,
+    /// which has all of its lifetimes **falsely extended** to `'static`. The
+    /// caller must ensure that the returned reference is only used in ways
+    /// that are valid for the value's _true_ lifetimes; in particular, it must
+    /// not be downcast and then used to copy or clone out data that could
+    /// outlive the borrows it contains.
+    unsafe fn as_std_any_unchecked(&self) -> &dyn core::any::Any;
+
+    /// Mutable version of [`as_std_any_unchecked`][Self::as_std_any_unchecked].
+    ///
+    /// # Safety
+    /// See [`as_std_any_unchecked`][Self::as_std_any_unchecked]; in addition,
+    /// the caller must not use the returned reference to write any values with
+    /// lifetimes shorter than the true lifetimes of the erased value.
+    unsafe fn as_std_any_mut_unchecked(&mut self) -> &mut dyn core::any::Any;
+
+    #[cfg(feature = "alloc")]
+    /// Owned version of [`as_std_any_unchecked`][Self::as_std_any_unchecked].
+    ///
+    /// # Safety
+    /// See [`as_std_any_unchecked`][Self::as_std_any_unchecked]; in addition,
+    /// the caller must ensure that the returned box is dropped before the true
+    /// lifetimes of the erased value end.
+    unsafe fn into_std_any_unchecked(self: Box<Self>) -> Box<dyn core::any::Any>;
 }
 
 impl<T: Transient, R: Transience> Any<R> for T
@@ -75,11 +129,71 @@ where
     fn type_id(&self) -> TypeId {
         TypeId::of::<T>()
     }
+
+    #[inline]
+    fn type_name(&self) -> &'static str {
+        type_name::<T>()
+    }
+
+    #[inline]
+    unsafe fn as_std_any_unchecked(&self) -> &dyn core::any::Any {
+        // The `Transient` trait guarantees that `T::Static` has the same layout
+        // as `T`; the caller is responsible for the extended lifetimes.
+        &*(self as *const T).cast::<T::Static>()
+    }
+
+    #[inline]
+    unsafe fn as_std_any_mut_unchecked(&mut self) -> &mut dyn core::any::Any {
+        // See `as_std_any_unchecked`
+        &mut *(self as *mut T).cast::<T::Static>()
+    }
+
+    #[cfg(feature = "alloc")]
+    #[inline]
+    unsafe fn into_std_any_unchecked(self: Box<Self>) -> Box<dyn core::any::Any> {
+        // See `as_std_any_unchecked`
+        Box::<T::Static>::from_raw(Box::into_raw(self).cast())
+    }
 }
 
-impl<R: Transience> std::fmt::Debug for dyn Any<R> + '_ {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("Any").finish_non_exhaustive()
+/// Conversions to the [`std::any::Any`] trait object, which are only safe when
+/// the value is known to be `'static`.
+impl dyn Any<()> + '_ {
+    /// Views the erased value as a [`std::any::Any`] trait object, for use with
+    /// code that is not aware of this crate.
+    ///
+    /// This is safe because only `'static` types (i.e. those with a `Transience`
+    /// of [`Timeless`][crate::Timeless]) can be erased to `dyn Any<()>`. To view
+    /// a `dyn Any<R>` with any other transience in this way, see the `unsafe`
+    /// [`Any::as_std_any_unchecked`] method.
+    ///
+    /// # Examples
+    /// ```
+    /// use transient::Any;
+    ///
+    /// let erased: &dyn Any = &5_usize;
+    /// let std_any: &dyn std::any::Any = erased.as_std_any();
+    /// assert_eq!(std_any.downcast_ref::<usize>(), Some(&5));
+    /// ```
+    #[inline]
+    pub fn as_std_any(&self) -> &dyn core::any::Any {
+        // Only timeless types can be erased to `dyn Any<()>`
+        unsafe { self.as_std_any_unchecked() }
+    }
+
+    /// Mutable version of `as_std_any`.
+    #[inline]
+    pub fn as_std_any_mut(&mut self) -> &mut dyn core::any::Any {
+        // Only timeless types can be erased to `dyn Any<()>`
+        unsafe { self.as_std_any_mut_unchecked() }
+    }
+
+    #[cfg(feature = "alloc")]
+    /// Owned version of `as_std_any`.
+    #[inline]
+    pub fn into_std_any(self: Box<Self>) -> Box<dyn core::any::Any> {
+        // Only timeless types can be erased to `dyn Any<()>`
+        unsafe { self.into_std_any_unchecked() }
     }
 }
 
@@ -90,8 +204,9 @@ impl<R: Transience> std::fmt::Debug for dyn Any<R> + '_ {
 /// Extension trait defining methods for downcasting the [`dyn Any<_>`][Any] trait
 /// object back into a concrete type.
 ///
-/// This trait has an implementation provided for the `dyn Any` trait object,
-/// as in not intended to be implemented by downstream types.
+/// This trait has implementations provided for the `dyn Any`, `dyn Any + Send`,
+/// and `dyn Any + Send + Sync` trait objects, and is not intended to be
+/// implemented by downstream types.
 pub trait Downcast<R: Transience> {
     /// Returns `true` if the concrete type of the erased object is `T`, which can
     /// be used to predict the outcome of calling the [`downcast`][Self::downcast]
@@ -105,6 +220,7 @@ pub trait Downcast<R: Transience> {
     /// when using this check in the implementation of `unsafe` code).
     fn is<T: Transient>(&self) -> bool;
 
+    #[cfg(feature = "alloc")]
     /// Attempt to downcast the box to a concrete type with its lifetime
     /// parameters restored, returning the original in the `Err` variant
     /// if the type was incorrect.
@@ -112,6 +228,37 @@ pub trait Downcast<R: Transience> {
     where
         T::Transience: CanRecoverFrom<R>;
 
+    #[cfg(feature = "alloc")]
+    /// Attempt to downcast the pinned box to a concrete type with its lifetime
+    /// parameters restored, returning the original in the `Err` variant
+    /// if the type was incorrect.
+    ///
+    /// The value is not moved by the downcast, so the returned box remains
+    /// pinned; this allows erased futures and self-referential types to be
+    /// restored after pinning them.
+    fn downcast_pin<T: Transient>(self: Pin<Box<Self>>) -> Result<Pin<Box<T>>, Pin<Box<Self>>>
+    where
+        T::Transience: CanRecoverFrom<R>;
+
+    #[cfg(feature = "alloc")]
+    /// Attempt to downcast the `Rc` to a concrete type with its lifetime
+    /// parameters restored, returning the original in the `Err` variant
+    /// if the type was incorrect.
+    fn downcast_rc<T: Transient>(self: Rc<Self>) -> Result<Rc<T>, Rc<Self>>
+    where
+        T::Transience: CanRecoverFrom<R>;
+
+    #[cfg(feature = "alloc")]
+    /// Attempt to downcast the `Arc` to a concrete type with its lifetime
+    /// parameters restored, returning the original in the `Err` variant
+    /// if the type was incorrect.
+    ///
+    /// Erasing to `dyn Any + Send + Sync` before wrapping the value in an `Arc`
+    /// allows the erased `Arc` to be shared between threads.
+    fn downcast_arc<T: Transient>(self: Arc<Self>) -> Result<Arc<T>, Arc<Self>>
+    where
+        T::Transience: CanRecoverFrom<R>;
+
     /// Returns a reference to the inner value with its lifetime parameters
     /// restored if it is of type `T`, or `None` if it isn't.
     fn downcast_ref<T: Transient>(&self) -> Option<&T>
@@ -120,10 +267,25 @@ pub trait Downcast<R: Transience> {
 
     /// Returns a mutable reference to the inner value with its lifetime
     /// parameters restored if it is of type `T`, or `None` if it isn't.
+    ///
+    /// This requires the trait object to be [`Invariant`] (e.g. `dyn Any<Inv>`).
+    /// A covariant `&mut dyn Any<Co<'short>>` may have been created from a
+    /// `&mut &'long str`, so the restored `&mut &'short str` could be used to
+    /// write a reference that only lives for `'short` into a place that the
+    /// original owner still reads as `&'long str`, leaving it dangling once
+    /// `'short` ends (and likewise for a contravariant one, in the opposite
+    /// direction). An invariant trait object always has the exact lifetimes of
+    /// the value it was created from, so nothing shorter can be written. To
+    /// mutate a covariant value, erase it as `Inv` instead, or [`downcast`] the
+    /// `Box` to take ownership of it.
+    ///
+    /// [`downcast`]: Downcast::downcast
     fn downcast_mut<T: Transient>(&mut self) -> Option<&mut T>
     where
-        T::Transience: CanRecoverFrom<R>;
+        T::Transience: CanRecoverFrom<R>,
+        R: Invariant;
 
+    #[cfg(feature = "alloc")]
     /// Downcasts the box to a concrete type without compile-time checks.
     ///
     /// For a safe alternative see [`downcast`][Downcast::downcast].
@@ -152,7 +314,8 @@ pub trait Downcast<R: Transience> {
 
     /// Downcasts the mutable reference to a concrete type without runtime checks.
     ///
-    /// For a safe alternative see [`downcast_mut`][Downcast::downcast_mut].
+    /// For a safe alternative see [`downcast_mut`][Downcast::downcast_mut], which
+    /// explains why the trait object must be [`Invariant`].
     ///
     /// # Safety
     /// The contained value must be of type `T::Static`; calling this method with
@@ -160,93 +323,168 @@ pub trait Downcast<R: Transience> {
     /// expected to uphold any lifetime guarantees, since the trait bounds handle
     /// this statically.
     unsafe fn downcast_mut_unchecked<T: Transient>(&mut self) -> &mut T
-    where
-        T::Transience: CanRecoverFrom<R>;
-}
-
-impl<R: Transience> Downcast<R> for dyn Any<R> + '_ {
-    #[inline]
-    fn is<T: Transient>(&self) -> bool {
-        self.type_id() == TypeId::of::<T>()
-    }
-
-    #[inline]
-    fn downcast<T: Transient>(self: Box<Self>) -> Result<Box<T>, Box<Self>>
     where
         T::Transience: CanRecoverFrom<R>,
-    {
-        if self.is::<T>() {
-            // We just confirmed that the type is correct.
-            Ok(unsafe { self.downcast_unchecked() })
-        } else {
-            Err(self)
-        }
-    }
+        R: Invariant;
+}
 
-    #[inline]
-    fn downcast_ref<T: Transient>(&self) -> Option<&T>
-    where
-        T::Transience: CanRecoverFrom<R>,
-    {
-        if self.is::<T>() {
-            // We just confirmed that the type is correct.
-            Some(unsafe { self.downcast_ref_unchecked() })
-        } else {
-            None
+/// Implements the [`Downcast`] and [`Debug`][std::fmt::Debug] traits for the
+/// given `dyn Any` trait objects (which only differ in their auto-traits).
+macro_rules! impl_dyn_any {
+    ($($ty:ty),* $(,)?) => {
+        $(
+        impl<R: Transience> core::fmt::Debug for $ty {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                f.debug_struct("Any").finish_non_exhaustive()
+            }
         }
-    }
 
-    #[inline]
-    fn downcast_mut<T: Transient>(&mut self) -> Option<&mut T>
-    where
-        T::Transience: CanRecoverFrom<R>,
-    {
-        if self.is::<T>() {
-            // We just confirmed that the type is correct.
-            Some(unsafe { self.downcast_mut_unchecked() })
-        } else {
-            None
+        impl<R: Transience> Downcast<R> for $ty {
+            #[inline]
+            fn is<T: Transient>(&self) -> bool {
+                self.type_id() == TypeId::of::<T>()
+            }
+
+            #[cfg(feature = "alloc")]
+            #[inline]
+            fn downcast<T: Transient>(self: Box<Self>) -> Result<Box<T>, Box<Self>>
+            where
+                T::Transience: CanRecoverFrom<R>,
+            {
+                if self.is::<T>() {
+                    // We just confirmed that the type is correct.
+                    Ok(unsafe { self.downcast_unchecked() })
+                } else {
+                    Err(self)
+                }
+            }
+
+            #[cfg(feature = "alloc")]
+            #[inline]
+            fn downcast_pin<T: Transient>(
+                self: Pin<Box<Self>>,
+            ) -> Result<Pin<Box<T>>, Pin<Box<Self>>>
+            where
+                T::Transience: CanRecoverFrom<R>,
+            {
+                if (*self).is::<T>() {
+                    // We just confirmed that the type is correct, and the value is
+                    // re-pinned at the same address since the downcast cannot move it.
+                    Ok(unsafe {
+                        let boxed = Pin::into_inner_unchecked(self);
+                        Pin::new_unchecked(boxed.downcast_unchecked())
+                    })
+                } else {
+                    Err(self)
+                }
+            }
+
+            #[cfg(feature = "alloc")]
+            #[inline]
+            fn downcast_rc<T: Transient>(self: Rc<Self>) -> Result<Rc<T>, Rc<Self>>
+            where
+                T::Transience: CanRecoverFrom<R>,
+            {
+                if self.is::<T>() {
+                    // We just confirmed that the type is correct, and the pointer
+                    // cast is safe for the same reasons as in `downcast_unchecked`.
+                    Ok(unsafe { Rc::from_raw(Rc::into_raw(self).cast()) })
+                } else {
+                    Err(self)
+                }
+            }
+
+            #[cfg(feature = "alloc")]
+            #[inline]
+            fn downcast_arc<T: Transient>(self: Arc<Self>) -> Result<Arc<T>, Arc<Self>>
+            where
+                T::Transience: CanRecoverFrom<R>,
+            {
+                if self.is::<T>() {
+                    // We just confirmed that the type is correct, and the pointer
+                    // cast is safe for the same reasons as in `downcast_unchecked`.
+                    Ok(unsafe { Arc::from_raw(Arc::into_raw(self).cast()) })
+                } else {
+                    Err(self)
+                }
+            }
+
+            #[inline]
+            fn downcast_ref<T: Transient>(&self) -> Option<&T>
+            where
+                T::Transience: CanRecoverFrom<R>,
+            {
+                if self.is::<T>() {
+                    // We just confirmed that the type is correct.
+                    Some(unsafe { self.downcast_ref_unchecked() })
+                } else {
+                    None
+                }
+            }
+
+            #[inline]
+            fn downcast_mut<T: Transient>(&mut self) -> Option<&mut T>
+            where
+                T::Transience: CanRecoverFrom<R>,
+                R: Invariant,
+            {
+                if self.is::<T>() {
+                    // We just confirmed that the type is correct.
+                    Some(unsafe { self.downcast_mut_unchecked() })
+                } else {
+                    None
+                }
+            }
+
+            #[cfg(feature = "alloc")]
+            #[inline]
+            unsafe fn downcast_unchecked<T: Transient>(self: Box<Self>) -> Box<T>
+            where
+                T::Transience: CanRecoverFrom<R>,
+            {
+                // The caller is expected to ensure that the inner type is `T::Static`,
+                // which the `Transient` trait guarantees has the same layout as `T`,
+                // so the pointer cast is safe. The trait bound on `T::Transience`
+                // ensures that the lifetime parameters of the returned type satisfy
+                // the necessary subtyping relationships.
+                Box::from_raw(Box::into_raw(self).cast())
+            }
+
+            #[inline]
+            unsafe fn downcast_ref_unchecked<T: Transient>(&self) -> &T
+            where
+                T::Transience: CanRecoverFrom<R>,
+            {
+                // The caller is expected to ensure that the inner type is `T::Static`,
+                // which the `Transient` trait guarantees has the same layout as `T`,
+                // so the pointer casts are safe. The trait bound on `T::Transience`
+                // ensures that the lifetime parameters of the returned type satisfy
+                // the necessary subtyping relationships.
+                &*(self as *const Self).cast()
+            }
+
+            #[inline]
+            unsafe fn downcast_mut_unchecked<T: Transient>(&mut self) -> &mut T
+            where
+                T::Transience: CanRecoverFrom<R>,
+                R: Invariant,
+            {
+                // The caller is expected to ensure that the inner type is `T::Static`,
+                // which the `Transient` trait guarantees has the same layout as `T`,
+                // so the pointer casts are safe. The trait bound on `T::Transience`
+                // ensures that the lifetime parameters of the returned type satisfy
+                // the necessary subtyping relationships, and the `Invariant` bound
+                // on `R` ensures that they match the original value exactly.
+                &mut *(self as *mut Self).cast()
+            }
         }
-    }
-
-    #[inline]
-    unsafe fn downcast_unchecked<T: Transient>(self: Box<Self>) -> Box<T>
-    where
-        T::Transience: CanRecoverFrom<R>,
-    {
-        // The caller is expected to ensure that the inner type is `T::Static`,
-        // which the `Transient` trait guarantees has the same layout as `T`,
-        // so the pointer cast is safe. The trait bound on `T::Transience`
-        // ensures that the lifetime parameters of the returned type satisfy
-        // the necessary subtyping relationships.
-        Box::from_raw(Box::into_raw(self).cast())
-    }
-
-    #[inline]
-    unsafe fn downcast_ref_unchecked<T: Transient>(&self) -> &T
-    where
-        T::Transience: CanRecoverFrom<R>,
-    {
-        // The caller is expected to ensure that the inner type is `T::Static`,
-        // which the `Transient` trait guarantees has the same layout as `T`,
-        // so the pointer casts are safe. The trait bound on `T::Transience`
-        // ensures that the lifetime parameters of the returned type satisfy
-        // the necessary subtyping relationships.
-        &*(self as *const Self).cast()
-    }
-
-    #[inline]
-    unsafe fn downcast_mut_unchecked<T: Transient>(&mut self) -> &mut T
-    where
-        T::Transience: CanRecoverFrom<R>,
-    {
-        // The caller is expected to ensure that the inner type is `T::Static`,
-        // which the `Transient` trait guarantees has the same layout as `T`,
-        // so the pointer casts are safe. The trait bound on `T::Transience`
-        // ensures that the lifetime parameters of the returned type satisfy
-        // the necessary subtyping relationships.
-        &mut *(self as *mut Self).cast()
-    }
+        )*
+    };
+}
+impl_dyn_any! {
+    dyn Any<R> + '_,
+    dyn Any<R> + Send + '_,
+    dyn Any<R> + Send + Sync + '_,
 }
 
 ///////////////////////////////////////////////////////////////////////////////
@@ -304,7 +542,7 @@ impl<R: Transience> Downcast<R> for dyn Any<R> + '_ {
 /// ```
 /// [`T::Static`]: Transient::Static
 #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
-pub struct TypeId(std::any::TypeId);
+pub struct TypeId(core::any::TypeId);
 
 impl TypeId {
     /// Returns the `TypeId` of the [`Transient`] type this generic function
@@ -333,7 +571,7 @@ impl TypeId {
     #[inline]
     pub fn of<T: Transient>() -> Self {
         let () = T::CHECK;
-        TypeId(std::any::TypeId::of::<T::Static>())
+        TypeId(core::any::TypeId::of::<T::Static>())
     }
 
     /// Returns the `TypeId` for the type of the given value.
@@ -350,42 +588,42 @@ impl TypeId {
     }
 }
 
-impl From<std::any::TypeId> for TypeId {
+impl From<core::any::TypeId> for TypeId {
     #[inline]
-    fn from(value: std::any::TypeId) -> Self {
+    fn from(value: core::any::TypeId) -> Self {
         TypeId(value)
     }
 }
 
-impl From<TypeId> for std::any::TypeId {
+impl From<TypeId> for core::any::TypeId {
     #[inline]
     fn from(value: TypeId) -> Self {
         value.0
     }
 }
 
-impl PartialEq<std::any::TypeId> for TypeId {
+impl PartialEq<core::any::TypeId> for TypeId {
     #[inline]
-    fn eq(&self, other: &std::any::TypeId) -> bool {
+    fn eq(&self, other: &core::any::TypeId) -> bool {
         self.0.eq(other)
     }
 }
 
-impl std::fmt::Debug for TypeId {
+impl core::fmt::Debug for TypeId {
     #[inline]
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         self.0.fmt(f)
     }
 }
 
-impl std::hash::Hash for TypeId {
+impl core::hash::Hash for TypeId {
     #[inline]
-    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
         self.0.hash(state)
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "alloc"))]
 #[allow(unused)]
 mod tests {
     use super::*;
@@ -544,8 +782,8 @@ mod tests {
 
         // owned `UsizeRef`
         let usize_ref = UsizeRef(&usize_.0);
-        let inv: Box<dyn Any<Inv>> = Box::new(usize_ref.clone());
-        let co: Box<dyn Any<Co>> = Box::new(usize_ref.clone());
+        let inv: Box<dyn Any<Inv>> = Box::new(usize_ref);
+        let co: Box<dyn Any<Co>> = Box::new(usize_ref);
         assert_eq!(inv.downcast::<UsizeRef>().unwrap().0, &5_usize);
         assert_eq!(co.downcast::<UsizeRef>().unwrap().0, &5_usize);
 
@@ -555,4 +793,168 @@ mod tests {
         assert_eq!(inv.downcast_ref::<UsizeRef>().unwrap().0, &5_usize);
         assert_eq!(co.downcast_ref::<UsizeRef>().unwrap().0, &5_usize);
     }
+
+    #[test]
+    fn test_rc() {
+        use crate::{Co, Inv};
+
+        let value = 5_usize;
+        let valref = &value;
+
+        let erased: Rc<dyn Any<Co>> = Rc::new(valref);
+        let shared = Rc::clone(&erased);
+        // a failed downcast returns the original `Rc`
+        let erased = erased.downcast_rc::<usize>().unwrap_err();
+        let restored: Rc<&usize> = erased.downcast_rc().unwrap();
+        assert_eq!(*restored, valref);
+        assert_eq!(Rc::strong_count(&restored), 2);
+        assert_eq!(shared.downcast_ref::<&usize>().unwrap(), &valref);
+
+        let erased: Rc<dyn Any<Inv>> = Rc::new(value);
+        assert_eq!(*erased.downcast_rc::<usize>().unwrap(), value);
+    }
+
+    #[test]
+    fn test_pin() {
+        use crate::Co;
+
+        let value = 5_usize;
+        let valref = &value;
+
+        let erased: Pin<Box<dyn Any<Co>>> = Box::pin(valref);
+        let address = &*erased as *const dyn Any<Co> as *const ();
+        // a failed downcast returns the original pinned box
+        let erased = erased.downcast_pin::<usize>().unwrap_err();
+        let restored: Pin<Box<&usize>> = erased.downcast_pin().unwrap();
+        assert_eq!(*restored, valref);
+        // the value was not moved by the downcast
+        assert_eq!(&*restored as *const &usize as *const (), address);
+    }
+
+    #[test]
+    fn test_panicking_drop() {
+        use crate::{collections::TransientVec, Co};
+        use std::panic::{catch_unwind, AssertUnwindSafe};
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        struct PanicOnDrop<'a>(&'a AtomicUsize);
+        unsafe impl<'a> Transient for PanicOnDrop<'a> {
+            type Static = PanicOnDrop<'static>;
+            type Transience = Co<'a>;
+        }
+        impl Drop for PanicOnDrop<'_> {
+            fn drop(&mut self) {
+                self.0.fetch_add(1, Ordering::SeqCst);
+                panic!("dropped");
+            }
+        }
+
+        let drops = AtomicUsize::new(0);
+        let drop_panics = |value: Box<dyn FnOnce() + '_>| {
+            assert!(catch_unwind(AssertUnwindSafe(value)).is_err());
+        };
+
+        // a failed downcast hands back ownership without dropping the value
+        let erased: Box<dyn Any<Co>> = Box::new(PanicOnDrop(&drops));
+        let erased = erased.downcast::<usize>().unwrap_err();
+        assert_eq!(drops.load(Ordering::SeqCst), 0);
+        drop_panics(Box::new(move || drop(erased)));
+        assert_eq!(drops.load(Ordering::SeqCst), 1);
+
+        // a successful downcast transfers ownership to the restored box
+        let erased: Box<dyn Any<Co>> = Box::new(PanicOnDrop(&drops));
+        let restored = erased.downcast::<PanicOnDrop>().unwrap();
+        drop_panics(Box::new(move || drop(restored)));
+        assert_eq!(drops.load(Ordering::SeqCst), 2);
+
+        // values left behind by `drain_as` are dropped exactly once with the vec
+        let mut vec = TransientVec::new();
+        vec.push(PanicOnDrop(&drops));
+        vec.push(5_usize);
+        assert_eq!(vec.drain_as::<usize>(), [5]);
+        assert_eq!(drops.load(Ordering::SeqCst), 2);
+        drop_panics(Box::new(move || drop(vec)));
+        assert_eq!(drops.load(Ordering::SeqCst), 3);
+    }
+
+    #[test]
+    fn test_arc() {
+        use crate::Co;
+
+        let value = 5_usize;
+        let valref = &value;
+
+        let erased: Arc<dyn Any<Co> + Send + Sync> = Arc::new(valref);
+        let shared = Arc::clone(&erased);
+        std::thread::scope(|s| {
+            s.spawn(move || assert_eq!(**shared.downcast_arc::<&usize>().unwrap(), 5));
+        });
+        // a failed downcast returns the original `Arc`
+        let erased = erased.downcast_arc::<usize>().unwrap_err();
+        let restored: Arc<&usize> = erased.downcast_arc().unwrap();
+        assert_eq!(*restored, valref);
+        assert_eq!(Arc::strong_count(&restored), 1);
+    }
+
+    #[test]
+    fn test_std_any() {
+        use crate::Co;
+
+        let mut erased: Box<dyn Any> = Box::new(5_usize);
+        assert_eq!(erased.as_std_any().downcast_ref::<usize>(), Some(&5));
+        *erased.as_std_any_mut().downcast_mut::<usize>().unwrap() += 1;
+        let std_any: Box<dyn std::any::Any> = erased.into_std_any();
+        assert_eq!(*std_any.downcast::<usize>().unwrap(), 6);
+
+        // non-'static types are viewed as their `Static` type
+        let value = 5_usize;
+        let erased: &dyn Any<Co> = &&value;
+        let std_any = unsafe { erased.as_std_any_unchecked() };
+        assert!(std_any.is::<&'static usize>());
+        assert_eq!(std_any.type_id(), std::any::TypeId::of::<&usize>());
+    }
+
+    #[test]
+    fn test_type_name() {
+        use crate::{Co, Inv};
+
+        let value = 5_usize;
+        let erased: Vec<Box<dyn Any<Inv>>> = vec![Box::new(value), Box::new(&value)];
+        assert_eq!(erased[0].type_name(), "usize");
+        assert_eq!(erased[1].type_name(), "&usize");
+
+        let string = "qwer".to_string();
+        let erased: &dyn Any<Co> = &string.as_str();
+        assert_eq!(erased.type_name(), type_name_of_val(&string.as_str()));
+        assert!(erased.downcast_ref::<usize>().is_none());
+    }
+
+    #[test]
+    fn test_send_sync() {
+        use crate::Co;
+
+        let value = 5_usize;
+        let valref = &value;
+
+        // owned `&usize` sent to another thread
+        let send: Box<dyn Any<Co> + Send> = Box::new(valref);
+        let restored = std::thread::scope(|s| {
+            s.spawn(move || send.downcast::<&usize>().unwrap())
+                .join()
+                .unwrap()
+        });
+        assert_eq!(*restored, valref);
+
+        // borrowed `&usize` shared with another thread
+        let sync: &(dyn Any<Co> + Send + Sync) = &valref;
+        std::thread::scope(|s| {
+            s.spawn(|| assert_eq!(sync.downcast_ref::<&usize>().unwrap(), &valref));
+        });
+
+        // failed downcasts return the original box with its auto-traits intact
+        let send: Box<dyn Any<Co> + Send + Sync> = Box::new(valref);
+        let send: Box<dyn Any<Co> + Send + Sync> = send.downcast::<usize>().unwrap_err();
+        assert!(send.is::<&usize>());
+        assert_eq!(**send.downcast_ref::<&usize>().unwrap(), 5_usize);
+    }
 }
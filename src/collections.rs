@@ -0,0 +1,396 @@
+//! Collections of type-erased values that share a common lifetime, built on
+//! the [`dyn Any<Inv<'src>>`][Any] trait object.
+#[cfg(feature = "std")]
+use crate::any::TypeId;
+use crate::{
+    any::{Any, Downcast},
+    transience::{CanRecoverFrom, CanTranscendTo, Inv},
+    transient::Transient,
+};
+use alloc::{boxed::Box, vec::Vec};
+use core::marker::PhantomData;
+#[cfg(feature = "std")]
+use std::collections::HashMap;
+
+/// A map storing at most one value of each type, where every value may borrow
+/// data for the shared lifetime `'src`.
+///
+/// This is the non-`'static` analogue of the "type map" pattern, which cannot
+/// be expressed using `std::any::Any`. Values are keyed by their [`TypeId`],
+/// and are stored as `Box<dyn Any<Inv<'src>>>` so that any type whose
+/// `Transience` can be unified with `Inv<'src>` can be inserted. Since `'src`
+/// is fixed for the whole map, values are restored with their lifetimes
+/// shortened to `'src` (for covariant types) or exactly as they were inserted
+/// (for invariant types).
+///
+/// # Examples
+/// ```
+/// use transient::collections::TransientAnyMap;
+///
+/// let name = "request".to_string();
+/// let mut map = TransientAnyMap::new();
+/// map.insert(name.as_str());
+/// map.insert(5_usize);
+///
+/// assert_eq!(map.get::<&str>(), Some(&"request"));
+/// *map.get_mut::<usize>().unwrap() += 1;
+/// assert_eq!(map.remove::<usize>(), Some(6));
+/// assert!(!map.contains::<usize>());
+/// ```
+#[cfg(feature = "std")]
+#[derive(Debug, Default)]
+pub struct TransientAnyMap<'src> {
+    map: HashMap<TypeId, Box<dyn Any<Inv<'src>> + 'src>>,
+}
+
+#[cfg(feature = "std")]
+impl<'src> TransientAnyMap<'src> {
+    /// Creates an empty `TransientAnyMap`.
+    #[inline]
+    pub fn new() -> Self {
+        TransientAnyMap {
+            map: HashMap::new(),
+        }
+    }
+
+    /// Inserts a value into the map, returning the previous value of the
+    /// same type if one was present.
+    pub fn insert<T>(&mut self, value: T) -> Option<T>
+    where
+        T: Transient + 'src,
+        T::Transience: CanTranscendTo<Inv<'src>> + CanRecoverFrom<Inv<'src>>,
+    {
+        let previous = self.map.insert(TypeId::of::<T>(), Box::new(value))?;
+        // The key guarantees that the previous value was also a `T`
+        Some(*unsafe { previous.downcast_unchecked::<T>() })
+    }
+
+    /// Returns a reference to the value of type `T`, if one is present.
+    pub fn get<T>(&self) -> Option<&T>
+    where
+        T: Transient,
+        T::Transience: CanRecoverFrom<Inv<'src>>,
+    {
+        self.map.get(&TypeId::of::<T>())?.downcast_ref()
+    }
+
+    /// Returns a mutable reference to the value of type `T`, if one is present.
+    pub fn get_mut<T>(&mut self) -> Option<&mut T>
+    where
+        T: Transient,
+        T::Transience: CanRecoverFrom<Inv<'src>>,
+    {
+        self.map.get_mut(&TypeId::of::<T>())?.downcast_mut()
+    }
+
+    /// Returns a mutable reference to the value of type `T`, first inserting
+    /// the value returned by `f` if one is not already present.
+    pub fn get_or_insert_with<T, F>(&mut self, f: F) -> &mut T
+    where
+        T: Transient + 'src,
+        T::Transience: CanTranscendTo<Inv<'src>> + CanRecoverFrom<Inv<'src>>,
+        F: FnOnce() -> T,
+    {
+        let value = self
+            .map
+            .entry(TypeId::of::<T>())
+            .or_insert_with(|| Box::new(f()));
+        // The key guarantees that the value is a `T`
+        unsafe { value.downcast_mut_unchecked::<T>() }
+    }
+
+    /// Removes the value of type `T` from the map and returns it, if one was
+    /// present.
+    pub fn remove<T>(&mut self) -> Option<T>
+    where
+        T: Transient,
+        T::Transience: CanRecoverFrom<Inv<'src>>,
+    {
+        let value = self.map.remove(&TypeId::of::<T>())?;
+        // The key guarantees that the value is a `T`
+        Some(*unsafe { value.downcast_unchecked::<T>() })
+    }
+
+    /// Returns `true` if the map contains a value of type `T`.
+    #[inline]
+    pub fn contains<T: Transient>(&self) -> bool {
+        self.map.contains_key(&TypeId::of::<T>())
+    }
+
+    /// Returns the number of values in the map.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    /// Returns `true` if the map contains no values.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+
+    /// Removes all values from the map.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.map.clear()
+    }
+}
+
+/// An ordered collection of type-erased values of any type, where every value
+/// may borrow data for the shared lifetime `'src`.
+///
+/// Unlike [`TransientAnyMap`], this collection preserves insertion order and
+/// can hold any number of values of the same type. Values are stored as
+/// `Box<dyn Any<Inv<'src>>>`, so pushing a value that borrows data for a
+/// lifetime shorter than `'src` is rejected by the compiler.
+///
+/// # Examples
+/// ```
+/// use transient::collections::TransientVec;
+///
+/// let (a, b) = ("a".to_string(), "b".to_string());
+/// let mut events = TransientVec::new();
+/// events.push(a.as_str());
+/// events.push(1_usize);
+/// events.push(b.as_str());
+///
+/// let strs: Vec<&&str> = events.iter_as::<&str>().collect();
+/// assert_eq!(strs, [&"a", &"b"]);
+///
+/// assert_eq!(events.drain_as::<usize>(), [1]);
+/// assert_eq!(events.len(), 2);
+/// ```
+#[derive(Debug, Default)]
+pub struct TransientVec<'src> {
+    vec: Vec<Box<dyn Any<Inv<'src>> + 'src>>,
+}
+
+impl<'src> TransientVec<'src> {
+    /// Creates an empty `TransientVec`.
+    #[inline]
+    pub fn new() -> Self {
+        TransientVec { vec: Vec::new() }
+    }
+
+    /// Appends a value to the back of the collection.
+    #[inline]
+    pub fn push<T>(&mut self, value: T)
+    where
+        T: Transient + 'src,
+        T::Transience: CanTranscendTo<Inv<'src>>,
+    {
+        self.vec.push(Box::new(value))
+    }
+
+    /// Returns the erased value at the given index, if there is one.
+    #[inline]
+    pub fn get(&self, index: usize) -> Option<&(dyn Any<Inv<'src>> + 'src)> {
+        self.vec.get(index).map(|value| &**value)
+    }
+
+    /// Returns an iterator over the erased values in insertion order.
+    pub fn iter(&self) -> impl Iterator<Item = &(dyn Any<Inv<'src>> + 'src)> {
+        self.vec.iter().map(|value| &**value)
+    }
+
+    /// Returns an iterator over the values of type `T` in insertion order,
+    /// skipping values of any other type.
+    #[inline]
+    pub fn iter_as<T>(&self) -> IterAs<'_, 'src, T>
+    where
+        T: Transient,
+        T::Transience: CanRecoverFrom<Inv<'src>>,
+    {
+        IterAs {
+            iter: self.vec.iter(),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Returns an iterator over mutable references to the values of type `T`
+    /// in insertion order, skipping values of any other type.
+    #[inline]
+    pub fn iter_mut_as<T>(&mut self) -> IterMutAs<'_, 'src, T>
+    where
+        T: Transient,
+        T::Transience: CanRecoverFrom<Inv<'src>>,
+    {
+        IterMutAs {
+            iter: self.vec.iter_mut(),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Removes all values of type `T` from the collection and returns them
+    /// in insertion order, preserving the order of the remaining values.
+    pub fn drain_as<T>(&mut self) -> Vec<T>
+    where
+        T: Transient,
+        T::Transience: CanRecoverFrom<Inv<'src>>,
+    {
+        let (matching, remaining) = core::mem::take(&mut self.vec)
+            .into_iter()
+            .partition::<Vec<_>, _>(|value| value.is::<T>());
+        self.vec = remaining;
+        matching
+            .into_iter()
+            // The partition guarantees that each value is a `T`
+            .map(|value| *unsafe { value.downcast_unchecked::<T>() })
+            .collect()
+    }
+
+    /// Returns the number of values in the collection.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.vec.len()
+    }
+
+    /// Returns `true` if the collection contains no values.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.vec.is_empty()
+    }
+
+    /// Removes all values from the collection.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.vec.clear()
+    }
+}
+
+/// An iterator over the values of type `T` in a [`TransientVec`].
+///
+/// This struct is created by the [`TransientVec::iter_as`] method.
+#[derive(Debug)]
+pub struct IterAs<'a, 'src, T> {
+    iter: core::slice::Iter<'a, Box<dyn Any<Inv<'src>> + 'src>>,
+    _marker: PhantomData<&'a T>,
+}
+
+impl<'a, 'src, T> Iterator for IterAs<'a, 'src, T>
+where
+    T: Transient,
+    T::Transience: CanRecoverFrom<Inv<'src>>,
+{
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        self.iter.find_map(|value| value.downcast_ref())
+    }
+}
+
+/// An iterator over mutable references to the values of type `T` in a
+/// [`TransientVec`].
+///
+/// This struct is created by the [`TransientVec::iter_mut_as`] method.
+#[derive(Debug)]
+pub struct IterMutAs<'a, 'src, T> {
+    iter: core::slice::IterMut<'a, Box<dyn Any<Inv<'src>> + 'src>>,
+    _marker: PhantomData<&'a mut T>,
+}
+
+impl<'a, 'src, T> Iterator for IterMutAs<'a, 'src, T>
+where
+    T: Transient,
+    T::Transience: CanRecoverFrom<Inv<'src>>,
+{
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<&'a mut T> {
+        self.iter.find_map(|value| value.downcast_mut())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Co;
+
+    #[derive(Debug, PartialEq)]
+    struct Request<'a> {
+        path: &'a str,
+    }
+    unsafe impl<'a> Transient for Request<'a> {
+        type Static = Request<'static>;
+        type Transience = Co<'a>;
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_any_map() {
+        let path = "/index.html".to_string();
+        let mut map = TransientAnyMap::new();
+        assert!(map.is_empty());
+
+        assert_eq!(map.insert(Request { path: &path }), None);
+        assert_eq!(map.insert(path.as_str()), None);
+        assert_eq!(map.insert(5_usize), None);
+        assert_eq!(map.len(), 3);
+
+        // replacing a value returns the previous one
+        assert_eq!(map.insert(6_usize), Some(5));
+        assert_eq!(map.len(), 3);
+
+        assert_eq!(map.get::<Request>(), Some(&Request { path: &path }));
+        assert_eq!(map.get::<&str>(), Some(&"/index.html"));
+        assert_eq!(map.get::<i32>(), None);
+
+        map.get_mut::<Request>().unwrap().path = "/other.html";
+        assert_eq!(map.remove::<Request>().unwrap().path, "/other.html");
+        assert!(!map.contains::<Request>());
+        assert!(map.contains::<usize>());
+
+        map.clear();
+        assert!(map.is_empty());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_any_map_get_or_insert_with() {
+        let path = "/index.html".to_string();
+        let mut map = TransientAnyMap::new();
+
+        *map.get_or_insert_with(|| 0_usize) += 1;
+        *map.get_or_insert_with::<usize, _>(|| unreachable!()) += 1;
+        assert_eq!(map.get::<usize>(), Some(&2));
+
+        let request = map.get_or_insert_with(|| Request { path: &path });
+        request.path = "/other.html";
+        assert_eq!(map.remove::<Request>().unwrap().path, "/other.html");
+        assert_eq!(map.len(), 1);
+    }
+
+    #[test]
+    fn test_vec() {
+        let path = "/index.html".to_string();
+        let mut vec = TransientVec::new();
+        assert!(vec.is_empty());
+
+        vec.push(Request { path: &path });
+        vec.push(1_usize);
+        vec.push(path.as_str());
+        vec.push(2_usize);
+        vec.push(Request {
+            path: "/other.html",
+        });
+        assert_eq!(vec.len(), 5);
+        assert!(vec.get(0).unwrap().is::<Request>());
+        assert!(vec.get(5).is_none());
+
+        let paths: Vec<_> = vec.iter_as::<Request>().map(|r| r.path).collect();
+        assert_eq!(paths, ["/index.html", "/other.html"]);
+        vec.iter_mut_as::<usize>().for_each(|n| *n *= 10);
+
+        assert_eq!(vec.drain_as::<usize>(), [10, 20]);
+        assert_eq!(vec.len(), 3);
+        // the remaining values keep their order
+        assert!(vec
+            .iter()
+            .map(|v| v.is::<Request>())
+            .eq([true, false, true]));
+        assert!(vec.drain_as::<usize>().is_empty());
+
+        vec.clear();
+        assert!(vec.is_empty());
+    }
+}
@@ -35,16 +35,19 @@
 //! - Supports types with any number of generic lifetime parameters with arbitrary
 //!   variance combinations
 //! - Supports types with any number of generic type parameters
+//! - Supports the `dyn Any + Send` and `dyn Any + Send + Sync` variants of the
+//!   trait object for sending erased values between threads
 //! - Provides the [`macro@Transient`] `derive` macro to implement the `Transient`
-//!   trait for most types
+//!   trait for most types, and the [`transient!`] macro for the remaining cases
+//! - Supports `no_std` environments by disabling the default `std` feature, with
+//!   the `alloc` feature re-enabling the functionality that requires `Box`
+//! - Provides compile-time checks for testing hand-written `Transient` impls in
+//!   the `test_support` module, behind the `test-support` feature
 //!
 //! # Limitations
 //! - Requires a single `unsafe` trait to be implemented for types wishing to
 //!   utilize the crate's functionality; however, this trait is usually trivial
 //!   to safely implement, and a `derive` macro is provided for common cases
-//! - Only emulates the non-`Send`/`Sync` variant of the stdlib's `Any` trait
-//!   for now, but support for `Any + Send` and `Any + Send + Sync` can be added
-//!   if requested
 //! - Only `Sized` types are supported. Removing this restriction would be
 //!   trivial, but makes it awkward to name generic types that require their
 //!   parameters to be `T: Sized` since `T::Static: Sized` must be explicitly
@@ -198,8 +201,11 @@
 //! any transience which is more (or equally) conservative than its own. For
 //! example, a type `struct S<'long>(&'long i32)` that implements `Transient`
 //! with a `Transience` of `Co<'long>` can be erased to `dyn Any<_>` with any
-//! compatible transience such as `Co<'long>`, `Co<'short>`, `Inv<'long>`, and
-//! `Inv<'short>`.
+//! compatible transience such as `Co<'long>`, `Co<'short>`, and `Inv<'long>`.
+//! It can also be erased to `Inv<'short>`, since the compiler is free to
+//! shorten the lifetime of `S<'long>` to `S<'short>` before it is erased; the
+//! transition to `Inv` itself always preserves the lifetime, which is what
+//! allows a `&mut dyn Any<Inv<'a>>` to be safely downcast to a `&mut T`.
 //!
 //! #### Mixing _covariant_ and _contravariant_ types
 //! As a result of the flexibility discussed above, the following example of
@@ -311,10 +317,21 @@
 //! [_subtyping and variance_]: https://doc.rust-lang.org/nomicon/subtyping.html
 //! [*the quality or state of being transient*]: https://www.merriam-webster.com/dictionary/transience
 #![deny(missing_docs, clippy::missing_safety_doc)]
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
 
 pub mod any;
+#[cfg(feature = "alloc")]
+pub mod collections;
+#[cfg(any(feature = "test-support", test))]
+pub mod test_support;
 pub mod transience;
 
+// only defines the `transient!` macro, which is exported at the crate root
+mod macros;
+
 // intentionally non-public to avoid naming conflicts with the crate
 mod transient;
 
@@ -327,7 +344,7 @@ pub use crate::transient::{Static, Transient};
 #[doc(inline)]
 pub use transience::{Co, Contra, Inv, Timeless, Transience};
 
-pub use transience::{CanRecoverFrom, CanTranscendTo};
+pub use transience::{CanRecoverFrom, CanTranscendTo, Direction, Invariant, Meet, Variance};
 
 #[cfg(feature = "derive")]
 pub use transient_derive::Transient;
@@ -339,7 +356,7 @@ pub mod tr {
 }
 
 #[cfg(test)]
-pub mod tests;
+mod tests;
 
 #[cfg(doctest)]
 #[doc = include_str!("../README.md")]
@@ -0,0 +1,273 @@
+//! Defines the [`transient!`] macro for implementing the [`Transient`] trait
+//! on types that can't use the derive macro, and the [`assert_covariant!`] and
+//! [`assert_contravariant!`] macros for checking the variance of a type.
+//!
+//! [`Transient`]: crate::Transient
+
+/// Declarative alternative to the [`Transient`] derive macro that generates the
+/// same `unsafe impl` for one or more types.
+///
+/// Each type is listed by name along with its generic parameters (in the same
+/// order as its definition), an optional `where` clause, and a trailing `;`. As
+/// with the derive macro, a `T: 'static` bound is added for each type parameter,
+/// the `Static` type is formed by replacing every lifetime with `'static`, and
+/// the `Transience` is a tuple with one element per lifetime in declaration
+/// order (or `Timeless` if there are none). Bounds on type parameters must be
+/// given in the `where` clause.
+///
+/// Since this macro does not have access to the type's fields, the variance of
+/// each lifetime defaults to _invariant_ and can be overridden by following the
+/// lifetime with `= <keyword>`, using the same keywords as the derive macro's
+/// `#[variance(...)]` attribute (`inv`, `unsafe_co`, `unsafe_contra`, or their
+/// long forms). As with the derive macro, choosing a variance other than
+/// invariant is `unsafe` and requires reviewing the [safety docs] first.
+///
+/// # Examples
+/// ```
+/// use transient::{transient, Any, Co, Contra, Downcast, Inv};
+///
+/// struct Unit;
+/// struct Wrapper<'a, T>(&'a T);
+/// struct Mixed<'a, 'b, 'c, T, const N: usize>(fn(&'a str), &'b [T; N], &'c mut T);
+///
+/// transient! {
+///     Unit;
+///     Wrapper<'a = unsafe_co, T> where T: Clone;
+///     Mixed<'a = unsafe_contra, 'b = unsafe_covariant, 'c, T, const N: usize>;
+/// }
+/// // this generates the following impls:
+/// // unsafe impl transient::Transient for Unit {
+/// //     type Static = Unit;
+/// //     type Transience = transient::Timeless;
+/// // }
+/// // unsafe impl<'a, T: 'static> transient::Transient for Wrapper<'a, T>
+/// // where T: Clone {
+/// //     type Static = Wrapper<'static, T>;
+/// //     type Transience = transient::Co<'a>;
+/// // }
+/// // unsafe impl<'a, 'b, 'c, T: 'static, const N: usize> transient::Transient
+/// // for Mixed<'a, 'b, 'c, T, N> {
+/// //     type Static = Mixed<'static, 'static, 'static, T, N>;
+/// //     type Transience = (
+/// //         transient::Contra<'a>, transient::Co<'b>, transient::Inv<'c>
+/// //     );
+/// // }
+///
+/// let value = 5;
+/// let erased: &dyn Any<Co> = &Wrapper(&value);
+/// assert_eq!(erased.downcast_ref::<Wrapper<i32>>().unwrap().0, &5);
+///
+/// let mut value = 5;
+/// let mixed = Mixed(|_| {}, &[1, 2, 3], &mut value);
+/// let erased: &dyn Any<(Contra, Co, Inv)> = &mixed;
+/// assert!(erased.is::<Mixed<i32, 3>>());
+/// ```
+///
+/// [`Transient`]: crate::Transient
+/// [safety docs]: crate::Transient#safety
+#[macro_export]
+macro_rules! transient {
+    // ----- variance keywords ----- //
+    (@variance inv $lt:lifetime) => { $crate::Inv<$lt> };
+    (@variance invariant $lt:lifetime) => { $crate::Inv<$lt> };
+    (@variance unsafe_co $lt:lifetime) => { $crate::Co<$lt> };
+    (@variance unsafe_covariant $lt:lifetime) => { $crate::Co<$lt> };
+    (@variance unsafe_contra $lt:lifetime) => { $crate::Contra<$lt> };
+    (@variance unsafe_contravariant $lt:lifetime) => { $crate::Contra<$lt> };
+    (@variance $safe:ident $lt:lifetime) => {
+        ::core::compile_error!(::core::concat!(
+            "Unexpected variance '", ::core::stringify!($safe), "'! Note that choosing ",
+            "a variance other than `inv` is `unsafe` and requires the 'unsafe_' prefix ",
+            "(e.g. `unsafe_co` or `unsafe_contra`) after reviewing the safety docs ",
+            "for the `transient::Transient` trait."
+        ))
+    };
+
+    // ----- generic parameters ----- //
+    (@generics $name:ident $impl:tt $args:tt $static:tt $tr:tt [, $($rest:tt)*]) => {
+        $crate::transient!(@generics $name $impl $args $static $tr [$($rest)*]);
+    };
+    (@generics $name:ident [$($impl:tt)*] [$($args:tt)*] [$($static:tt)*] [$($tr:ty),*]
+        [$lt:lifetime = $variance:ident $($rest:tt)*]
+    ) => {
+        $crate::transient!(@generics $name
+            [$($impl)* $lt,] [$($args)* $lt,] [$($static)* 'static,]
+            [$($tr,)* $crate::transient!(@variance $variance $lt)]
+            [$($rest)*]
+        );
+    };
+    (@generics $name:ident [$($impl:tt)*] [$($args:tt)*] [$($static:tt)*] [$($tr:ty),*]
+        [$lt:lifetime $($rest:tt)*]
+    ) => {
+        $crate::transient!(@generics $name
+            [$($impl)* $lt,] [$($args)* $lt,] [$($static)* 'static,]
+            [$($tr,)* $crate::Inv<$lt>]
+            [$($rest)*]
+        );
+    };
+    (@generics $name:ident [$($impl:tt)*] [$($args:tt)*] [$($static:tt)*] $tr:tt
+        [const $param:ident : $ty:ident $($rest:tt)*]
+    ) => {
+        $crate::transient!(@generics $name
+            [$($impl)* const $param: $ty,] [$($args)* $param,] [$($static)* $param,] $tr
+            [$($rest)*]
+        );
+    };
+    (@generics $name:ident $impl:tt $args:tt $static:tt $tr:tt
+        [$param:ident : $($bound:tt)*]
+    ) => {
+        ::core::compile_error!(::core::concat!(
+            "Unexpected bound on the type parameter `", ::core::stringify!($param), "`! ",
+            "Bounds on type parameters must be given in the `where` clause instead ",
+            "(e.g. `where ", ::core::stringify!($param), ": Clone`)."
+        ));
+    };
+    (@generics $name:ident [$($impl:tt)*] [$($args:tt)*] [$($static:tt)*] $tr:tt
+        [$param:ident $($rest:tt)*]
+    ) => {
+        $crate::transient!(@generics $name
+            [$($impl)* $param: 'static,] [$($args)* $param,] [$($static)* $param,] $tr
+            [$($rest)*]
+        );
+    };
+    (@generics $name:ident $impl:tt $args:tt $static:tt $tr:tt [> $($rest:tt)*]) => {
+        $crate::transient!(@where $name $impl $args $static $tr [] $($rest)*);
+    };
+
+    // ----- where clause ----- //
+    (@where $name:ident $impl:tt $args:tt $static:tt $tr:tt $where:tt ; $($rest:tt)*) => {
+        $crate::transient!(@emit $name $impl $args $static $tr $where);
+        $crate::transient!($($rest)*);
+    };
+    (@where $name:ident $impl:tt $args:tt $static:tt $tr:tt [$($where:tt)*] $t:tt $($rest:tt)*) => {
+        $crate::transient!(@where $name $impl $args $static $tr [$($where)* $t] $($rest)*);
+    };
+
+    // ----- generated impl ----- //
+    (@emit $name:ident [$($impl:tt)*] [$($args:tt)*] [$($static:tt)*] $tr:tt [$($where:tt)*]) => {
+        unsafe impl<$($impl)*> $crate::Transient for $name<$($args)*> $($where)* {
+            type Static = $name<$($static)*>;
+            type Transience = $crate::transient!(@transience $tr);
+        }
+    };
+    (@transience []) => { $crate::Timeless };
+    (@transience [$tr:ty]) => { $tr };
+    (@transience [$($tr:ty),*]) => { ($($tr),*) };
+
+    // ----- entry points ----- //
+    () => {};
+    ($name:ident < $($rest:tt)*) => {
+        $crate::transient!(@generics $name [] [] [] [] [$($rest)*]);
+    };
+    ($name:ident $($rest:tt)*) => {
+        $crate::transient!(@where $name [] [] [] [] [] $($rest)*);
+    };
+}
+
+/// Compile-time assertion that a type is _covariant_ with respect to one of its
+/// lifetime parameters, for checking the choice of [`Co`] in a hand-written
+/// [`Transient`] impl.
+///
+/// The lifetime to check is introduced with a `for<'a>` binder, and any other
+/// lifetimes must be given concretely (e.g. `'static`). Multiple types can be
+/// checked in one invocation by separating them with `;`. The assertion is
+/// implemented by a function that coerces the type from a longer to a shorter
+/// lifetime, so it fails with a "lifetime may not live long enough" error if
+/// the type is not actually covariant.
+///
+/// Note that there is no corresponding `assert_invariant!`, since [`Inv`] is
+/// sound for any type and using it by mistake can only make the `Transient`
+/// impl more restrictive than necessary.
+#[cfg_attr(
+    feature = "test-support",
+    doc = "\nThis checks the type itself rather than its `Transient` impl; the \
+    [`assert_declared_covariant`](crate::test_support::assert_declared_covariant) \
+    function checks that the declared `Transience` is covariant instead.\n"
+)]
+///
+/// # Examples
+/// ```
+/// use transient::{assert_covariant, Co, Transient};
+///
+/// struct Borrowed<'a, T>(&'a T);
+/// unsafe impl<'a, T: 'static> Transient for Borrowed<'a, T> {
+///     type Static = Borrowed<'static, T>;
+///     type Transience = Co<'a>;
+/// }
+/// assert_covariant! {
+///     for<'a> Borrowed<'a, usize>;
+///     for<'a> (&'a str, Option<&'static mut String>);
+/// }
+/// ```
+/// A type which is invariant is rejected:
+/// ```compile_fail
+/// # use transient::assert_covariant;
+/// assert_covariant!(for<'a> std::cell::Cell<&'a str>);
+/// ```
+///
+/// [`Co`]: crate::Co
+/// [`Inv`]: crate::Inv
+/// [`Transient`]: crate::Transient
+#[macro_export]
+macro_rules! assert_covariant {
+    ($(for<$lt:lifetime> $ty:ty);+ $(;)?) => {
+        $(
+        const _: () = {
+            #[allow(dead_code)]
+            type Checked<$lt> = $ty;
+            #[allow(dead_code)]
+            fn assert_covariant<'short, 'long: 'short>(
+                value: Checked<'long>,
+            ) -> Checked<'short> {
+                value
+            }
+        };
+        )+
+    };
+}
+
+/// Compile-time assertion that a type is _contravariant_ with respect to one of
+/// its lifetime parameters, for checking the choice of [`Contra`] in a
+/// hand-written [`Transient`] impl.
+///
+/// This is the counterpart of [`assert_covariant!`] and uses the same syntax,
+/// but coerces the type from a shorter to a longer lifetime instead.
+#[cfg_attr(
+    feature = "test-support",
+    doc = "\nThis checks the type itself rather than its `Transient` impl; the \
+    [`assert_declared_contravariant`](crate::test_support::assert_declared_contravariant) \
+    function checks that the declared `Transience` is contravariant instead.\n"
+)]
+///
+/// # Examples
+/// ```
+/// use transient::assert_contravariant;
+///
+/// struct Callback<'a>(fn(&'a str) -> usize);
+/// assert_contravariant!(for<'a> Callback<'a>);
+/// ```
+/// A type which is covariant is rejected:
+/// ```compile_fail
+/// # use transient::assert_contravariant;
+/// assert_contravariant!(for<'a> &'a str);
+/// ```
+///
+/// [`Contra`]: crate::Contra
+/// [`Transient`]: crate::Transient
+#[macro_export]
+macro_rules! assert_contravariant {
+    ($(for<$lt:lifetime> $ty:ty);+ $(;)?) => {
+        $(
+        const _: () = {
+            #[allow(dead_code)]
+            type Checked<$lt> = $ty;
+            #[allow(dead_code)]
+            fn assert_contravariant<'short, 'long: 'short>(
+                value: Checked<'short>,
+            ) -> Checked<'long> {
+                value
+            }
+        };
+        )+
+    };
+}
@@ -0,0 +1,104 @@
+//! Compile-time checks for validating hand-written [`Transient`] impls in the
+//! tests of downstream crates (requires the `test-support` feature).
+//!
+//! Each function in this module has an empty body and only compiles when the
+//! relationship it describes holds for the given type. Calling one in a
+//! regular test therefore asserts that the relationship holds, while calling
+//! one in a `compile_fail` doctest (or a `trybuild` compile-fail case) asserts
+//! that it is rejected. The latter is how this crate tests its own impls, since
+//! an unsound `Transient` impl usually manifests as an illegal transition that
+//! is accepted when it should not be.
+//!
+//! These functions only check the _declared_ `Transience` of a type; the
+//! [`assert_covariant!`] and [`assert_contravariant!`] macros can be used to
+//! check that the declaration matches the actual variance of the type.
+//!
+//! # Examples
+//! ```
+//! use transient::{test_support::*, Co, Inv, Transient};
+//!
+//! struct MutRef<'a>(&'a mut &'a str);
+//! unsafe impl<'a> Transient for MutRef<'a> {
+//!     type Static = MutRef<'static>;
+//!     type Transience = Inv<'a>;
+//! }
+//!
+//! fn check<'a>() {
+//!     assert_transcends::<MutRef<'a>, Inv<'a>>();
+//!     assert_recovers::<MutRef<'a>, Inv<'a>>();
+//! }
+//! ```
+//! The invariant impl must then reject being erased as covariant:
+//! ```compile_fail
+//! # use transient::{test_support::*, Inv, Transient};
+//! # struct MutRef<'a>(&'a mut &'a str);
+//! # unsafe impl<'a> Transient for MutRef<'a> {
+//! #     type Static = MutRef<'static>;
+//! #     type Transience = Inv<'a>;
+//! # }
+//! fn check<'a>() {
+//!     assert_declared_covariant::<'a, MutRef<'a>>();
+//! }
+//! ```
+//!
+//! [`assert_covariant!`]: crate::assert_covariant
+//! [`assert_contravariant!`]: crate::assert_contravariant
+use crate::{
+    transience::{CanRecoverFrom, CanTranscendTo, Co, Contra},
+    Transience, Transient,
+};
+
+/// Compiles only if a `T` can be erased to a `dyn Any<R>` trait object.
+#[inline]
+pub fn assert_transcends<T, R>()
+where
+    T: Transient,
+    T::Transience: CanTranscendTo<R>,
+    R: Transience,
+{
+}
+
+/// Compiles only if a `T` can be restored from a `dyn Any<R>` trait object.
+#[inline]
+pub fn assert_recovers<T, R>()
+where
+    T: Transient,
+    T::Transience: CanRecoverFrom<R>,
+    R: Transience,
+{
+}
+
+/// Compiles only if a `T` can be erased to a `dyn Any<Co<'a>>` trait object,
+/// which requires the `Transience` of `T` to be covariant (or `Timeless`).
+///
+/// This is intended for types with a single lifetime, which should be passed
+/// as `'a` (e.g. `assert_declared_covariant::<'a, S<'a>>()`). Only the declared
+/// `Transience` is checked; the [`assert_covariant!`] macro checks that the type
+/// itself is covariant.
+///
+/// [`assert_covariant!`]: crate::assert_covariant
+#[inline]
+pub fn assert_declared_covariant<'a, T>()
+where
+    T: Transient,
+    T::Transience: CanTranscendTo<Co<'a>>,
+{
+}
+
+/// Compiles only if a `T` can be erased to a `dyn Any<Contra<'a>>` trait
+/// object, which requires the `Transience` of `T` to be contravariant (or
+/// `Timeless`).
+///
+/// This is intended for types with a single lifetime, which should be passed
+/// as `'a` (e.g. `assert_declared_contravariant::<'a, S<'a>>()`). Only the
+/// declared `Transience` is checked; the [`assert_contravariant!`] macro checks
+/// that the type itself is contravariant.
+///
+/// [`assert_contravariant!`]: crate::assert_contravariant
+#[inline]
+pub fn assert_declared_contravariant<'a, T>()
+where
+    T: Transient,
+    T::Transience: CanTranscendTo<Contra<'a>>,
+{
+}
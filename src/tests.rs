@@ -1,7 +1,13 @@
+/// Compile-time check that two types are identical.
+fn same<A: Same<B>, B>() {}
+trait Same<T> {}
+impl<T> Same<T> for T {}
+
 /// Tests for a simple struct with no generic parameters.
 mod double {
     use crate::{Inv, Transient};
 
+    #[allow(dead_code)]
     #[derive(Debug, Clone, PartialEq, Eq)]
     struct S<'a, T1, T2> {
         value1: &'a T1,
@@ -14,6 +20,7 @@ mod double {
 }
 
 /// Tests for a simple struct with no generic parameters.
+#[cfg(feature = "alloc")]
 mod basic {
     use crate::*;
 
@@ -31,15 +38,31 @@ mod basic {
         let value = "qwer".to_string();
         let original: S<'_> = S { value: &value };
         let erased: Box<dyn Any<Co<'_>> + '_> = Box::new(original.clone());
+        let restored: Box<S<'_>> = erased.downcast::<S<'_>>().unwrap();
+        assert_eq!(*restored, original);
 
-        // `S::Transience` is `Co<'a>` se we can erase to `Any<Co<'a>>`, but
-        // instead we downgraded to `Any<Inv<'a>>`. Now can't restore it b/c
-        // the bounds require a subtype of `Co<'a>`, which `Inv<'a>` is not.
-        // However, we need to allow the transition, but only when restoring,
-        // not transcending.
+        // `S::Transience` is `Co<'a>` so we could erase to `Any<Co<'a>>`, but
+        // instead we downgrade to `Any<Inv<'a>>`. Restoring the original type
+        // is still allowed since `Co<'a>: CanRecoverFrom<Inv<'a>>`, even though
+        // `Inv<'a>` could never be transcended back into `Co<'a>`.
+        let erased: Box<dyn Any<Inv<'_>> + '_> = Box::new(original.clone());
         let restored: Box<S<'_>> = erased.downcast::<S<'_>>().unwrap();
         assert_eq!(*restored, original);
     }
+    #[test]
+    pub(super) fn test_owned_contra() {
+        #[derive(Debug, Clone)]
+        struct F<'a>(fn(&'a str) -> usize);
+        unsafe impl<'a> Transient for F<'a> {
+            type Static = F<'static>;
+            type Transience = Contra<'a>;
+        }
+        // a contravariant type can also be restored after erasing to `Inv`
+        let erased: Box<dyn Any<Inv<'_>>> = Box::new(F(str::len));
+        let restored: Box<F<'_>> = erased.downcast::<F<'_>>().unwrap();
+        assert_eq!((restored.0)("four"), 4);
+    }
+
     #[test]
     pub(super) fn test_ref() {
         // single lifetime (derived `Transient` impl)
@@ -54,14 +77,25 @@ mod basic {
     pub(super) fn test_mut() {
         let value = "qwer".to_string();
         let mut original = S { value: &value };
-        let erased: &mut dyn Any<Co> = &mut original;
+        let erased: &mut dyn Any<Inv> = &mut original;
         assert_eq!(erased.type_id(), TypeId::of::<S>());
         let restored = erased.downcast_mut::<S>().unwrap().clone();
         assert_eq!(restored, original);
     }
+    #[test]
+    pub(super) fn test_mut_write() {
+        // the restored `&mut S` has the exact lifetime of the original, so a
+        // value borrowing for the same lifetime can be written through it
+        let (first, second) = ("qwer".to_string(), "asdf".to_string());
+        let mut original = S { value: &first };
+        let erased: &mut dyn Any<Inv> = &mut original;
+        *erased.downcast_mut::<S>().unwrap() = S { value: &second };
+        assert_eq!(original.value, "asdf");
+    }
 }
 
 /// Tests for a struct with generic parameters.
+#[cfg(feature = "alloc")]
 mod generics {
     use crate::any::*;
     use crate::*;
@@ -107,7 +141,9 @@ mod generics {
     }
 }
 
+// some of the fail tests use `Box`, `Vec`, and `String`
 #[test]
+#[cfg(feature = "alloc")]
 fn variance_tests() {
     let t = trybuild::TestCases::new();
     t.compile_fail("tests/fail/*.rs");
@@ -119,7 +155,7 @@ mod mixed_lifetimes {
 
     type ContraCo<'s, 'l> = (Contra<'s>, Co<'l>);
 
-    #[derive(Debug, Clone, PartialEq, Eq)]
+    #[derive(Debug, Clone)]
     struct M<'s, 'l> {
         func: fn(&'s str) -> &'static str,
         string: &'l str,
@@ -162,3 +198,688 @@ mod mixed_lifetimes {
         assert_eq!(erased_long.type_id(), TypeId::of::<M>());
     }
 }
+
+/// Compile-time checks of the allowed transitions between transiences.
+#[allow(dead_code)]
+mod transitions {
+    use crate::{CanRecoverFrom, CanTranscendTo, Co, Contra, Inv, Timeless, Transience};
+
+    fn transcends<Src: CanTranscendTo<Dst>, Dst: Transience>() {}
+    fn recovers<Dst: CanRecoverFrom<Src>, Src: Transience>() {}
+
+    #[test]
+    fn test_transcend() {
+        fn check<'short, 'long: 'short>() {
+            // `Timeless` can transcend to anything
+            transcends::<Timeless, Timeless>();
+            transcends::<Timeless, Co<'short>>();
+            transcends::<Timeless, Contra<'long>>();
+            transcends::<Timeless, Inv<'short>>();
+            // `Co` can shorten, but only becomes `Inv` at the same lifetime
+            transcends::<Co<'long>, Co<'long>>();
+            transcends::<Co<'long>, Co<'short>>();
+            transcends::<Co<'long>, Inv<'long>>();
+            // `Contra` can lengthen, but only becomes `Inv` at the same lifetime
+            transcends::<Contra<'short>, Contra<'short>>();
+            transcends::<Contra<'short>, Contra<'long>>();
+            transcends::<Contra<'short>, Inv<'short>>();
+            // `Inv` can only stay the same
+            transcends::<Inv<'short>, Inv<'short>>();
+            // tuples transcend component-wise
+            transcends::<(Co<'long>, Contra<'short>), (Co<'short>, Contra<'long>)>();
+            transcends::<(Co<'long>, Inv<'short>), (Inv<'long>, Inv<'short>)>();
+            // and scalars can transcend to uniform tuples
+            transcends::<Co<'long>, (Co<'short>, Inv<'long>)>();
+            transcends::<(Co<'long>, Co<'long>), Co<'short>>();
+        }
+        check::<'_, 'static>();
+    }
+
+    #[test]
+    fn test_recover() {
+        fn check<'short, 'long: 'short>() {
+            // `Timeless` can be recovered from anything
+            recovers::<Timeless, Co<'short>>();
+            recovers::<Timeless, Contra<'long>>();
+            recovers::<Timeless, Inv<'short>>();
+            // `Co` can be recovered with a shorter lifetime
+            recovers::<Co<'short>, Co<'long>>();
+            recovers::<Inv<'short>, Co<'long>>();
+            // `Contra` can be recovered with a longer lifetime
+            recovers::<Contra<'long>, Contra<'short>>();
+            recovers::<Inv<'long>, Contra<'short>>();
+            // any scalar can be recovered from `Inv` with the same lifetime
+            recovers::<Co<'short>, Inv<'short>>();
+            recovers::<Contra<'short>, Inv<'short>>();
+            recovers::<Inv<'short>, Inv<'short>>();
+            // tuples recover component-wise
+            recovers::<(Co<'short>, Contra<'long>), (Inv<'short>, Contra<'short>)>();
+        }
+        check::<'_, 'static>();
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_transcend_methods() {
+        use crate::{Any, Downcast, Transient};
+
+        let string = "qwer".to_string();
+        let mut original = string.as_str();
+        let erased: &dyn Any<Co> = original.transcend_ref::<Co>();
+        assert_eq!(erased.downcast_ref::<&str>(), Some(&"qwer"));
+        let erased: &mut dyn Any<Inv> = original.transcend_mut::<Inv>();
+        *erased.downcast_mut::<&str>().unwrap() = "asdf";
+        assert_eq!(original, "asdf");
+        let erased = Box::new(original).transcend::<(Inv, Inv)>();
+        assert_eq!(*erased.downcast::<&str>().unwrap(), "asdf");
+    }
+
+    #[test]
+    fn test_meet() {
+        use super::same;
+        use crate::Meet;
+
+        /// Checks the output of the meet, and that both inputs can transcend to it
+        fn meet<A, B, O>()
+        where
+            A: Meet<B, Output = O> + CanTranscendTo<O>,
+            B: Transience + CanTranscendTo<O>,
+        {
+        }
+
+        #[allow(clippy::extra_unused_lifetimes)]
+        fn check<'a>() {
+            meet::<Co<'a>, Co<'a>, Co<'a>>();
+            meet::<Co<'a>, Contra<'a>, Inv<'a>>();
+            meet::<Co<'a>, Inv<'a>, Inv<'a>>();
+            meet::<Contra<'a>, Co<'a>, Inv<'a>>();
+            meet::<Contra<'a>, Contra<'a>, Contra<'a>>();
+            meet::<Contra<'a>, Inv<'a>, Inv<'a>>();
+            meet::<Inv<'a>, Co<'a>, Inv<'a>>();
+            meet::<Inv<'a>, Contra<'a>, Inv<'a>>();
+            meet::<Inv<'a>, Inv<'a>, Inv<'a>>();
+            // `Timeless` is the identity
+            meet::<Timeless, Co<'a>, Co<'a>>();
+            meet::<Contra<'a>, Timeless, Contra<'a>>();
+            same::<<Timeless as Meet<Timeless>>::Output, Timeless>();
+            // tuples meet component-wise
+            meet::<(Co<'a>, Contra<'a>), (Co<'a>, Co<'a>), (Co<'a>, Inv<'a>)>();
+            meet::<(Co<'a>, ()), (Inv<'a>, Co<'a>), (Inv<'a>, Co<'a>)>();
+            meet::<(Co<'a>, Contra<'a>), Timeless, (Co<'a>, Contra<'a>)>();
+        }
+        check();
+    }
+
+    #[test]
+    fn test_variance_direction() {
+        use crate::{Direction, Variance};
+
+        fn direction<R: Variance>() -> Direction {
+            R::DIRECTION
+        }
+        assert_eq!(direction::<Co>(), Direction::Covariant);
+        assert_eq!(direction::<Contra>(), Direction::Contravariant);
+        assert_eq!(direction::<Inv>(), Direction::Invariant);
+    }
+}
+
+/// Tests for a struct with three independent lifetimes of mixed variance.
+#[allow(dead_code)]
+mod triple_lifetimes {
+    use crate::*;
+
+    type Mixed<'s, 'l, 'i> = (Contra<'s>, Co<'l>, Inv<'i>);
+
+    #[derive(Debug)]
+    struct T<'s, 'l, 'i> {
+        func: fn(&'s str) -> usize,
+        string: &'l str,
+        cell: std::cell::Cell<&'i str>,
+    }
+    unsafe impl<'s, 'l, 'i> Transient for T<'s, 'l, 'i> {
+        type Static = T<'static, 'static, 'static>;
+        type Transience = Mixed<'s, 'l, 'i>;
+    }
+
+    /// The first (contravariant) lifetime lengthens from `'short` to `'long`,
+    /// the second (covariant) lifetime shortens from `'long` to `'short`, and
+    /// the third (invariant) lifetime stays the same.
+    fn rearrange<'b, 'short, 'long: 'short>(
+        value: &'b T<'short, 'long, 'short>,
+    ) -> &'b dyn Any<Mixed<'long, 'short, 'short>> {
+        value
+    }
+
+    #[test]
+    fn test_rearrange() {
+        let string = "qwer".to_string();
+        let original = T {
+            func: str::len,
+            string: "static",
+            cell: std::cell::Cell::new(&string),
+        };
+        let erased = rearrange(&original);
+        assert_eq!(erased.type_id(), TypeId::of::<T>());
+        let restored = erased.downcast_ref::<T>().unwrap();
+        assert_eq!((restored.func)(restored.string), 6);
+        assert_eq!(restored.cell.get(), "qwer");
+
+        // the tuple can also be collapsed into a single `Inv`
+        let erased: &dyn Any<Inv> = &original;
+        assert!(erased.downcast_ref::<T>().is_some());
+    }
+}
+
+/// Tests for the impls generated by the `transient!` macro.
+#[allow(dead_code)]
+#[cfg(feature = "alloc")]
+mod macro_impls {
+    use super::same;
+    use crate::*;
+
+    struct NoGenerics;
+    struct TypeOnly<T>(T);
+    struct LifetimeOnly<'a>(&'a str);
+    struct Mixed<'a, 'b, 'c, T, const N: usize, U>(fn(&'a T), &'b [U; N], &'c mut U);
+    struct Bounded<'a, T>(&'a T);
+
+    crate::assert_covariant! {
+        for<'a> LifetimeOnly<'a>;
+        for<'b> Mixed<'static, 'b, 'static, u8, 2, i8>;
+    }
+    crate::assert_contravariant!(for<'a> Mixed<'a, 'static, 'static, u8, 2, i8>);
+
+    crate::transient! {
+        NoGenerics;
+        TypeOnly<T>;
+        LifetimeOnly<'a = unsafe_co>;
+        Mixed<'a = unsafe_contravariant, 'b = unsafe_covariant, 'c, T, const N: usize, U,>;
+        Bounded<'a = inv, T> where T: Clone + PartialEq<T>;
+    }
+
+    #[test]
+    fn test_generated_types() {
+        #[allow(clippy::extra_unused_lifetimes)]
+        fn check<'a, 'b, 'c>() {
+            same::<<NoGenerics as Transient>::Static, NoGenerics>();
+            same::<<NoGenerics as Transient>::Transience, Timeless>();
+            same::<<TypeOnly<usize> as Transient>::Static, TypeOnly<usize>>();
+            same::<<TypeOnly<usize> as Transient>::Transience, Timeless>();
+            same::<<LifetimeOnly<'a> as Transient>::Static, LifetimeOnly<'static>>();
+            same::<<LifetimeOnly<'a> as Transient>::Transience, Co<'a>>();
+            same::<
+                <Mixed<'a, 'b, 'c, u8, 2, i8> as Transient>::Static,
+                Mixed<'static, 'static, 'static, u8, 2, i8>,
+            >();
+            same::<
+                <Mixed<'a, 'b, 'c, u8, 2, i8> as Transient>::Transience,
+                (Contra<'a>, Co<'b>, Inv<'c>),
+            >();
+            same::<<Bounded<'a, String> as Transient>::Static, Bounded<'static, String>>();
+            same::<<Bounded<'a, String> as Transient>::Transience, Inv<'a>>();
+        }
+        check();
+    }
+
+    #[test]
+    fn test_erase() {
+        let string = "qwer".to_string();
+        let original = LifetimeOnly(&string);
+        let erased: &dyn Any<Co> = &original;
+        assert_eq!(erased.downcast_ref::<LifetimeOnly>().unwrap().0, "qwer");
+
+        let original = Bounded(&string);
+        let erased: Box<dyn Any<Inv> + '_> = Box::new(original);
+        assert_eq!(erased.downcast::<Bounded<String>>().unwrap().0, "qwer");
+    }
+}
+
+/// Smoke tests for erasing borrowed values without the `alloc` feature.
+mod no_alloc {
+    use crate::*;
+
+    #[test]
+    fn test_erase_ref() {
+        let value = 5_usize;
+        let valref = &value;
+        let erased: &dyn Any<Co> = &valref;
+        assert!(erased.is::<&usize>());
+        assert_eq!(erased.downcast_ref::<&usize>(), Some(&valref));
+        assert_eq!(erased.downcast_ref::<&u32>(), None);
+    }
+
+    #[test]
+    fn test_erase_mut() {
+        let mut original = "qwer";
+        let erased: &mut dyn Any<Inv> = &mut original;
+        *erased.downcast_mut::<&str>().unwrap() = "asdf";
+        assert_eq!(original, "asdf");
+    }
+}
+
+/// Tests for the `Transient` impls of `Cow` with unsized borrowed types.
+#[cfg(feature = "alloc")]
+mod cow {
+    use crate::*;
+    use std::borrow::Cow;
+
+    #[test]
+    fn test_str() {
+        let string = "qwer".to_string();
+        let borrowed: Cow<'_, str> = Cow::Borrowed(&string);
+        let erased: Box<dyn Any<Co> + '_> = Box::new(borrowed);
+        let restored = erased.downcast::<Cow<str>>().unwrap();
+        assert!(matches!(*restored, Cow::Borrowed("qwer")));
+
+        let owned: Cow<'_, str> = Cow::Owned(string.clone());
+        let erased: &dyn Any<Co> = &owned;
+        assert!(erased.downcast_ref::<&str>().is_none());
+        let restored = erased.downcast_ref::<Cow<str>>().unwrap();
+        assert!(matches!(restored, Cow::Owned(s) if s == "qwer"));
+    }
+
+    #[test]
+    fn test_slice() {
+        let (a, b) = ("a".to_string(), "b".to_string());
+        let values = [a.as_str(), b.as_str()];
+        let borrowed: Cow<'_, [&str]> = Cow::Borrowed(&values);
+        let erased: &dyn Any<(Co, Co)> = &borrowed;
+        assert_eq!(**erased.downcast_ref::<Cow<[&str]>>().unwrap(), ["a", "b"]);
+
+        let owned: Cow<'_, [usize]> = Cow::Owned(vec![1, 2]);
+        let erased: Box<dyn Any<Co>> = Box::new(owned);
+        let restored = erased.downcast::<Cow<[usize]>>().unwrap();
+        assert!(matches!(*restored, Cow::Owned(ref v) if v == &[1, 2]));
+    }
+}
+
+/// Tests locking in the variance of the built-in impls for references.
+#[cfg(feature = "alloc")]
+mod refs {
+    use super::same;
+    use crate::*;
+
+    #[test]
+    fn test_transience() {
+        #[allow(clippy::extra_unused_lifetimes)]
+        fn check<'a, 'b: 'a>() {
+            same::<<&'a str as Transient>::Transience, Co<'a>>();
+            same::<<&'a [u8] as Transient>::Transience, (Co<'a>, ())>();
+            same::<<&'a [&'b str] as Transient>::Transience, (Co<'a>, Co<'b>)>();
+            same::<<&'a &'b str as Transient>::Transience, (Co<'a>, Co<'b>)>();
+            same::<<&'a i32 as Transient>::Transience, Co<'a>>();
+            same::<<&'a mut i32 as Transient>::Transience, Co<'a>>();
+            same::<<&'a mut &'b str as Transient>::Transience, (Co<'a>, Inv<'b>)>();
+            same::<<Option<&'a bool> as Transient>::Transience, Co<'a>>();
+            same::<<&'a char as Transient>::Static, &'static char>();
+        }
+        check();
+    }
+
+    #[test]
+    fn test_str() {
+        let string = "qwer".to_string();
+        let original: &str = &string;
+        let erased: &dyn Any<Co> = &original;
+        assert_eq!(erased.downcast_ref::<&str>(), Some(&"qwer"));
+        assert!(erased.downcast_ref::<&[u8]>().is_none());
+    }
+
+    #[test]
+    fn test_mut() {
+        let mut value = 5_i32;
+        let original: &mut i32 = &mut value;
+        let erased: Box<dyn Any<Co> + '_> = Box::new(original);
+        let restored = erased.downcast::<&mut i32>().unwrap();
+        **restored += 1;
+        assert_eq!(value, 6);
+    }
+
+    #[test]
+    fn test_mut_downcast() {
+        // `&mut &str` erased as invariant in both lifetimes can be mutably
+        // downcast, and the inner `&str` can be replaced
+        let (first, second) = ("qwer".to_string(), "asdf".to_string());
+        let mut inner: &str = &first;
+        let mut original: &mut &str = &mut inner;
+        let erased: &mut dyn Any<(Inv, Inv)> = &mut original;
+        **erased.downcast_mut::<&mut &str>().unwrap() = &second;
+        assert_eq!(inner, "asdf");
+    }
+
+    #[test]
+    fn test_primitives() {
+        let (flag, letter) = (true, 'x');
+        let erased: [&dyn Any; 2] = [&flag, &letter];
+        assert_eq!(erased[0].downcast_ref::<bool>(), Some(&true));
+        assert_eq!(erased[1].downcast_ref::<char>(), Some(&'x'));
+        let erased: &dyn Any<Co> = &Some(&flag);
+        assert_eq!(erased.downcast_ref::<Option<&bool>>(), Some(&Some(&true)));
+    }
+}
+
+/// Tests for the `Transient` impls of tuples.
+#[cfg(feature = "alloc")]
+mod tuples {
+    use crate::*;
+
+    struct Callback<'a>(fn(&'a str) -> usize);
+    unsafe impl<'a> Transient for Callback<'a> {
+        type Static = Callback<'static>;
+        type Transience = Contra<'a>;
+    }
+
+    #[test]
+    fn test_covariant() {
+        let (string, value) = ("qwer".to_string(), 5_usize);
+        let original = (string.as_str(), &value);
+        let erased: &dyn Any<Co> = &original;
+        assert!(erased.downcast_ref::<(&str,)>().is_none());
+        assert_eq!(erased.downcast_ref::<(&str, &usize)>(), Some(&("qwer", &5)));
+    }
+
+    #[test]
+    fn test_mixed() {
+        let string = "qwer".to_string();
+        let original = (Callback(|s| s.len()), string.as_str());
+        // the `(Contra<'a>, Co<'a>)` transience collapses to `Inv<'a>`
+        let erased: Box<dyn Any<Inv> + '_> = Box::new(original);
+        let (callback, restored) = *erased.downcast::<(Callback, &str)>().unwrap();
+        assert_eq!((callback.0)(restored), 4);
+    }
+
+    #[test]
+    fn test_timeless() {
+        let original = (
+            1_u8,
+            2_u16,
+            3_u32,
+            4_u64,
+            5_i8,
+            6_i16,
+            7_i32,
+            "8".to_string(),
+        );
+        let erased: Box<dyn Any> = Box::new(original.clone());
+        assert_eq!(
+            erased.type_id(),
+            TypeId::of::<(u8, u16, u32, u64, i8, i16, i32, String)>()
+        );
+        let restored = erased.downcast::<(u8, u16, u32, u64, i8, i16, i32, String)>();
+        assert_eq!(*restored.unwrap(), original);
+        let erased: Box<dyn Any> = Box::new((5_usize,));
+        assert!(erased.into_std_any().is::<(usize,)>());
+    }
+}
+
+/// Tests for the compile-time checks in the `test_support` module.
+#[allow(dead_code)]
+mod test_support {
+    use crate::test_support::*;
+    use crate::*;
+
+    struct MutRef<'a>(&'a mut &'a str);
+    unsafe impl<'a> Transient for MutRef<'a> {
+        type Static = MutRef<'static>;
+        type Transience = Inv<'a>;
+    }
+
+    struct Callback<'a>(fn(&'a str));
+    unsafe impl<'a> Transient for Callback<'a> {
+        type Static = Callback<'static>;
+        type Transience = Contra<'a>;
+    }
+
+    #[test]
+    fn test_checks() {
+        fn check<'short, 'long: 'short>() {
+            assert_declared_covariant::<'short, &'long str>();
+            assert_declared_covariant::<'short, usize>();
+            assert_declared_contravariant::<'long, Callback<'short>>();
+            assert_declared_contravariant::<'short, usize>();
+            assert_transcends::<MutRef<'long>, Inv<'long>>();
+            assert_transcends::<&'long str, Inv<'long>>();
+            assert_transcends::<(&'long str, Callback<'short>), (Co<'short>, Inv<'short>)>();
+            assert_recovers::<MutRef<'long>, Inv<'long>>();
+            assert_recovers::<&'short str, Co<'long>>();
+        }
+        check();
+    }
+}
+
+/// Tests for the `Transient` impls of erased `Debug`, `Display`, and `Error` types.
+#[cfg(feature = "alloc")]
+mod trait_objects {
+    use super::same;
+    use crate::*;
+    use std::fmt::{Debug, Display};
+
+    #[test]
+    fn test_transience() {
+        #[allow(clippy::extra_unused_lifetimes)]
+        fn check<'a>() {
+            same::<<&'a (dyn Display + 'a) as Transient>::Transience, Co<'a>>();
+            same::<<&'a mut (dyn Display + 'a) as Transient>::Transience, Inv<'a>>();
+            same::<<Box<dyn Debug + Send + 'a> as Transient>::Transience, Co<'a>>();
+            // the `Error` impls require the `std` feature
+            #[cfg(feature = "std")]
+            same::<
+                <&'a (dyn std::error::Error + Send + Sync + 'a) as Transient>::Static,
+                &'static (dyn std::error::Error + Send + Sync + 'static),
+            >();
+        }
+        check();
+    }
+
+    #[test]
+    fn test_erase() {
+        let string = "qwer".to_string();
+        let borrowed: &str = &string;
+        let display: &dyn Display = &borrowed;
+        let erased: &dyn Any<Co> = &display;
+        let restored = erased.downcast_ref::<&dyn Display>().unwrap();
+        assert_eq!(restored.to_string(), "qwer");
+        // the erased type is the trait object, not the value behind it
+        assert!(!erased.is::<&&str>());
+        assert_eq!(erased.type_id(), TypeId::of::<&dyn Display>());
+
+        let boxed: Box<dyn Debug + Send + '_> = Box::new(borrowed);
+        let erased: Box<dyn Any<Co> + '_> = Box::new(boxed);
+        let restored = erased.downcast::<Box<dyn Debug + Send>>().unwrap();
+        assert_eq!(format!("{restored:?}"), "\"qwer\"");
+
+        let mut value = 5_usize;
+        let display: &mut dyn Display = &mut value;
+        let erased: Box<dyn Any<Inv> + '_> = Box::new(display);
+        assert!(erased.is::<&mut dyn Display>());
+        assert!(!erased.is::<&dyn Display>());
+    }
+}
+
+/// Tests for the `Transient` impls of pinned future trait objects.
+#[cfg(feature = "alloc")]
+mod futures {
+    use crate::*;
+    use std::future::Future;
+    use std::pin::Pin;
+
+    #[test]
+    fn test_pinned_mut() {
+        let string = "qwer".to_string();
+        let future = std::pin::pin!(async { string.len() });
+        let future: Pin<&mut (dyn Future<Output = usize> + '_)> = future;
+        let erased: Box<dyn Any<Inv> + '_> = Box::new(future);
+        assert!(!erased.is::<Pin<&mut (dyn Future<Output = usize> + Send)>>());
+        assert!(erased
+            .downcast::<Pin<&mut dyn Future<Output = usize>>>()
+            .is_ok());
+    }
+
+    #[test]
+    fn test_pinned_box() {
+        let string = "qwer".to_string();
+        let future: Pin<Box<dyn Future<Output = usize> + Send + '_>> =
+            Box::pin(async { string.len() });
+        let erased: &dyn Any<Co> = &future;
+        assert!(erased.is::<Pin<Box<dyn Future<Output = usize> + Send>>>());
+        assert!(!erased.is::<Pin<Box<dyn Future<Output = ()> + Send>>>());
+    }
+}
+
+/// Tests for the `Transient` impls of containers forwarding to their contents.
+#[cfg(feature = "alloc")]
+mod containers {
+    use crate::*;
+
+    #[test]
+    fn test_vec() {
+        let (a, b) = ("a".to_string(), "b".to_string());
+        let original: Vec<&str> = vec![&a, &b];
+        let erased: Box<dyn Any<Co> + '_> = Box::new(original.clone());
+        assert!(!erased.is::<Vec<String>>());
+        let restored = erased.downcast::<Vec<&str>>().unwrap();
+        assert_eq!(*restored, original);
+
+        let original: Box<[&str]> = original.into_boxed_slice();
+        let erased: Box<dyn Any<Inv> + '_> = Box::new(original);
+        assert_eq!(
+            *erased.downcast::<Box<[&str]>>().unwrap(),
+            ["a", "b"].into()
+        );
+    }
+
+    #[test]
+    fn test_array() {
+        let (a, b, c) = ("a".to_string(), "b".to_string(), "c".to_string());
+        let original: [&str; 3] = [&a, &b, &c];
+        let erased: Box<dyn Any<Co> + '_> = Box::new(original);
+        assert!(!erased.is::<[&str; 2]>());
+        assert_eq!(*erased.downcast::<[&str; 3]>().unwrap(), original);
+
+        let digest = [0_u8; 32];
+        let refs: [&u8; 32] = core::array::from_fn(|i| &digest[i]);
+        let erased: &dyn Any<(Co, Co)> = &&refs;
+        assert_eq!(*erased.downcast_ref::<&[&u8; 32]>().unwrap(), &refs);
+    }
+
+    #[test]
+    fn test_option_result() {
+        let string = "qwer".to_string();
+        let original: Option<&str> = Some(&string);
+        let erased: &dyn Any<Co> = &original;
+        assert_eq!(erased.downcast_ref::<Option<&str>>(), Some(&Some("qwer")));
+
+        let original: Result<&str, String> = Ok(&string);
+        let erased: &dyn Any<Co> = &original;
+        assert_eq!(
+            erased.downcast_ref::<Result<&str, String>>().unwrap(),
+            &Ok("qwer")
+        );
+        assert!(!erased.is::<Result<&str, ()>>());
+    }
+
+    #[test]
+    fn test_unit_and_infallible() {
+        use core::convert::Infallible;
+
+        // generic code where the "no data" cases can occur
+        fn erase<T: Transient + 'static>(value: T) -> Box<dyn Any<T::Transience>> {
+            Box::new(value)
+        }
+        assert!(erase(()).is::<()>());
+
+        let parsed: Result<u32, Infallible> = Ok(5);
+        let erased = erase(parsed);
+        assert!(!erased.is::<Result<u32, ()>>());
+        let restored = erased.downcast::<Result<u32, Infallible>>().unwrap();
+        assert_eq!(*restored, Ok(5));
+
+        let string = "qwer".to_string();
+        let original: Result<&str, Infallible> = Ok(&string);
+        let erased: &dyn Any<Co> = &original;
+        assert!(erased.is::<Result<&str, Infallible>>());
+    }
+}
+
+/// Tests for the `Transient` impls of function pointers.
+mod fn_pointers {
+    use super::same;
+    use crate::*;
+
+    #[test]
+    fn test_transience() {
+        #[allow(clippy::extra_unused_lifetimes)]
+        fn check<'a>() {
+            same::<<fn(&'a str) -> usize as Transient>::Transience, Contra<'a>>();
+            same::<<fn(&'a mut String) as Transient>::Transience, Contra<'a>>();
+            same::<<fn() -> &'a str as Transient>::Transience, Co<'a>>();
+            same::<<fn(&str) -> usize as Transient>::Transience, Timeless>();
+            // further `'static` arguments after the reference
+            same::<<fn(&'a str, usize) -> bool as Transient>::Transience, Contra<'a>>();
+            same::<<fn(&'a mut String, u8, char) as Transient>::Transience, Contra<'a>>();
+            same::<<fn(&'a [u8], u8, u16, u32) -> u64 as Transient>::Transience, Contra<'a>>();
+            same::<<fn(&str, usize) -> bool as Transient>::Transience, Timeless>();
+            same::<<fn(&mut String, u8, u16, u32) as Transient>::Transience, Timeless>();
+        }
+        check();
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_argument() {
+        fn len(value: &str) -> usize {
+            value.len()
+        }
+        fn check<'short>(func: fn(&'short str) -> usize) {
+            // a function accepting a short-lived argument can be erased as
+            // requiring a longer-lived one
+            let erased: Box<dyn Any<Contra<'static>>> = Box::new(func);
+            assert!(!erased.is::<fn(&'static str)>());
+            let restored = erased.downcast::<fn(&'static str) -> usize>().unwrap();
+            assert_eq!(restored("qwer"), 4);
+        }
+        check(len);
+    }
+
+    #[test]
+    fn test_multiple_arguments() {
+        fn starts_with(value: &str, prefix: char, len: usize) -> bool {
+            value.starts_with(prefix) && value.len() == len
+        }
+        fn check<'short>(func: fn(&'short str, char, usize) -> bool) {
+            let erased: &dyn Any<Contra<'static>> = &func;
+            let restored = erased
+                .downcast_ref::<fn(&'static str, char, usize) -> bool>()
+                .unwrap();
+            assert!(restored("qwer", 'q', 4));
+        }
+        check(starts_with);
+    }
+
+    #[test]
+    fn test_return() {
+        fn name() -> &'static str {
+            "qwer"
+        }
+        #[allow(clippy::extra_unused_lifetimes)]
+        fn check<'short>(func: fn() -> &'static str) {
+            // a function returning a long-lived value can be erased as
+            // returning a shorter-lived one
+            let erased: &dyn Any<Co<'short>> = &func;
+            let restored = erased.downcast_ref::<fn() -> &'short str>().unwrap();
+            assert_eq!(restored(), "qwer");
+        }
+        check(name);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_higher_ranked() {
+        let func: fn(&str) -> usize = str::len;
+        let erased: Box<dyn Any> = Box::new(func);
+        // the higher-ranked type is distinct from any named-lifetime instance
+        assert!(!erased.is::<fn(&'static str) -> usize>());
+        let restored = erased.downcast::<fn(&str) -> usize>().unwrap();
+        assert_eq!(restored("qwer"), 4);
+    }
+}
@@ -2,7 +2,7 @@
 //! structs that implement it. This module also defines the [`CanTranscendTo`] and
 //! [`CanRecoverFrom`] traits that establish the allowable transitions between
 //! transiences.
-use std::marker::PhantomData;
+use core::marker::PhantomData;
 
 /// Marker trait for types used to establish the [variance] of a type with
 /// respect to each of its lifetime parameters, including [`Co`], [`Contra`],
@@ -75,7 +75,7 @@ use std::marker::PhantomData;
 ///   </tr>
 ///   <tr>
 ///     <th style="font-size: 12px;background-color:white"> <code>Co&lt'long&gt</code> </th>
-///     <td align="center" style="background-color:#FFD966;"> yes<sup>&lowast;</sup> </td>
+///     <td align="center" style="background-color:#FF9B9B;"> no </td>
 ///     <td align="center" style="background-color:lightgreen;"> yes </td>
 ///     <td align="center" style="background-color:#FFD966;"> yes<sup>&lowast;</sup> </td>
 ///     <td align="center" style="background-color:lightgrey;"> yes </td>
@@ -86,7 +86,7 @@ use std::marker::PhantomData;
 ///   <tr>
 ///     <th style="font-size: 12px;background-color:white"> <code>Contra&lt'short&gt</code> </th>
 ///     <td align="center" style="background-color:lightgreen;"> yes </td>
-///     <td align="center" style="background-color:#FFD966;"> yes<sup>&lowast;</sup> </td>
+///     <td align="center" style="background-color:#FF9B9B;"> no </td>
 ///     <td align="center" style="background-color:#FF9B9B;"> no </td>
 ///     <td align="center" style="background-color:#FF9B9B;"> no </td>
 ///     <td align="center" style="background-color:lightgrey;"> yes </td>
@@ -160,11 +160,21 @@ pub trait Transience: Sized + CanTranscendTo<Self> + CanRecoverFrom<Self> {}
 ///
 /// - `Timeless` --> any `R: Transience`
 /// - `Co<'long>` --> `Co<'short>`
-/// - `Co<'long>` --> `Inv<'short>`
+/// - `Co<'a>` --> `Inv<'a>`
 /// - `Contra<'short>` --> `Contra<'long>`
-/// - `Contra<'short>` --> `Inv<'long>`
+/// - `Contra<'a>` --> `Inv<'a>`
 /// - any `R: Transience` --> Self
 ///
+/// Note that a transition to `Inv` must preserve the lifetime _exactly_, even
+/// though the lifetime could be shortened (`Co`) or lengthened (`Contra`) on
+/// the way. This guarantees that a trait object whose transience is
+/// [`Invariant`] has the same lifetimes as the value inside it, which is what
+/// allows [`Downcast::downcast_mut`] to hand out a `&mut T` that can be written
+/// through. Covariant and contravariant values can still be erased as
+/// `Inv<'short>` (or `Inv<'long>`) by first letting the compiler coerce them to
+/// the target lifetime, which it does automatically for types that are actually
+/// covariant (or contravariant).
+///
 /// Additionally, this trait is _composable_ such that a tuple of transiences
 /// can safely implement it for another equal-length tuple when each of its
 /// components implement the trait for the corresponding component in the
@@ -183,6 +193,7 @@ pub trait Transience: Sized + CanTranscendTo<Self> + CanRecoverFrom<Self> {}
 ///
 /// [`T: Transient<Transience=R>`]: crate::Transient
 /// [`dyn Any<Other>`]: crate::Any
+/// [`Downcast::downcast_mut`]: crate::Downcast::downcast_mut
 /// [subtype]: https://doc.rust-lang.org/nomicon/subtyping.html
 pub unsafe trait CanTranscendTo<Other> {}
 
@@ -286,6 +297,118 @@ pub struct Contra<'a>(PhantomData<fn(&'a ())>);
 
 impl<'a> Transience for Contra<'a> {}
 
+/// The direction of the [variance] declared by one of the single-lifetime
+/// [`Variance`] markers.
+///
+/// [variance]: https://doc.rust-lang.org/nomicon/subtyping.html
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Direction {
+    /// The variance declared by [`Co`].
+    Covariant,
+    /// The variance declared by [`Contra`].
+    Contravariant,
+    /// The variance declared by [`Inv`].
+    Invariant,
+}
+
+/// Sealed trait implemented by the single-lifetime transiences [`Co`], [`Contra`],
+/// and [`Inv`], exposing the [`Direction`] of the variance they declare.
+///
+/// This allows generic code to be written over "any single-lifetime transience"
+/// and to branch on its variance, which is not possible using the [`Transience`]
+/// bound alone since it is also implemented by [`Timeless`] and tuples.
+///
+/// # Examples
+/// ```
+/// use transient::transience::{Co, Direction, Inv, Variance};
+///
+/// fn can_shorten<R: Variance>() -> bool {
+///     R::DIRECTION == Direction::Covariant
+/// }
+/// assert!(can_shorten::<Co>());
+/// assert!(!can_shorten::<Inv>());
+/// ```
+pub trait Variance: Transience + private::Sealed {
+    /// The direction of the variance declared by this marker.
+    const DIRECTION: Direction;
+}
+
+impl<'a> Variance for Inv<'a> {
+    const DIRECTION: Direction = Direction::Invariant;
+}
+
+impl<'a> Variance for Co<'a> {
+    const DIRECTION: Direction = Direction::Covariant;
+}
+
+impl<'a> Variance for Contra<'a> {
+    const DIRECTION: Direction = Direction::Contravariant;
+}
+
+/// Sealed trait implemented by the transiences that are _invariant_ with respect
+/// to all of their lifetimes, which includes [`Inv`], [`Timeless`], and tuples
+/// composed entirely of these.
+///
+/// A trait object such as `dyn Any<R>` where `R: Invariant` is guaranteed to
+/// carry the exact lifetimes of the value it was created from, since every
+/// transition to `Inv` (see [`CanTranscendTo`]) preserves the lifetime. This is
+/// required in order to safely obtain a mutable reference when downcasting; with
+/// a covariant `dyn Any<Co<'short>>`, for example, the restored `&mut T<'short>`
+/// could be used to write a `T<'short>` into a value that was originally a
+/// `T<'long>`, leaving it holding a dangling reference once `'short` ends.
+///
+/// # Examples
+/// ```
+/// use transient::{Any, Downcast, Inv, Transient};
+///
+/// let mut value = "qwer";
+/// let erased: &mut dyn Any<Inv> = value.transcend_mut::<Inv>();
+/// *erased.downcast_mut::<&str>().unwrap() = "asdf";
+/// assert_eq!(value, "asdf");
+/// ```
+/// Mutably downcasting a covariant trait object is rejected:
+/// ```compile_fail
+/// use transient::{Any, Co, Downcast};
+///
+/// fn shorten<'short>(erased: &mut dyn Any<Co<'short>>, value: &'short str) {
+///     *erased.downcast_mut::<&'short str>().unwrap() = value;
+/// }
+/// ```
+pub trait Invariant: Transience + private::Sealed {}
+
+impl Invariant for Timeless {}
+impl<'a> Invariant for Inv<'a> {}
+
+/// implements `Invariant` for tuples of invariant transiences
+macro_rules! impl_invariant_tuples {
+    ( $( ($($r:ident),+) );* $(;)? ) => {
+        $(
+        impl<$($r),+> private::Sealed for ($($r,)+) where $( $r: Invariant ),+ {}
+        impl<$($r),+> Invariant for ($($r,)+) where $( $r: Invariant ),+ {}
+        )*
+    };
+}
+impl_invariant_tuples! {
+    (R1);
+    (R1, R2);
+    (R1, R2, R3);
+    (R1, R2, R3, R4);
+    (R1, R2, R3, R4, R5);
+    (R1, R2, R3, R4, R5, R6);
+    (R1, R2, R3, R4, R5, R6, R7);
+    (R1, R2, R3, R4, R5, R6, R7, R8);
+}
+
+mod private {
+    /// Prevents [`Variance`][super::Variance] and [`Invariant`][super::Invariant]
+    /// from being implemented downstream.
+    pub trait Sealed {}
+    impl Sealed for super::Timeless {}
+    impl<'a> Sealed for super::Inv<'a> {}
+    impl<'a> Sealed for super::Co<'a> {}
+    impl<'a> Sealed for super::Contra<'a> {}
+}
+
 // ************************************************************************* //
 // ************************* SAFETY-CRITICAL LOGIC! ************************ //
 // ************************************************************************* //
@@ -303,13 +426,13 @@ unsafe impl<'a> CanRecoverFrom<Inv<'a>> for Inv<'a> {}
 unsafe impl<'a, 'b: 'a> CanTranscendTo<Co<'a>> for Co<'b> {}
 unsafe impl<'a, 'b: 'a> CanRecoverFrom<Co<'b>> for Co<'a> {}
 
-unsafe impl<'a, 'b: 'a> CanTranscendTo<Inv<'a>> for Co<'b> {}
+unsafe impl<'a> CanTranscendTo<Inv<'a>> for Co<'a> {}
 unsafe impl<'a, 'b: 'a> CanRecoverFrom<Co<'b>> for Inv<'a> {}
 
 unsafe impl<'a, 'b: 'a> CanTranscendTo<Contra<'b>> for Contra<'a> {}
 unsafe impl<'a, 'b: 'a> CanRecoverFrom<Contra<'a>> for Contra<'b> {}
 
-unsafe impl<'a, 'b: 'a> CanTranscendTo<Inv<'b>> for Contra<'a> {}
+unsafe impl<'a> CanTranscendTo<Inv<'a>> for Contra<'a> {}
 unsafe impl<'a, 'b: 'a> CanRecoverFrom<Contra<'a>> for Inv<'b> {}
 
 unsafe impl<'a> CanRecoverFrom<Inv<'a>> for Co<'a> {}
@@ -318,80 +441,67 @@ unsafe impl<'a> CanRecoverFrom<Inv<'a>> for Contra<'a> {}
 // ************************************************************** //
 
 /// Private macro implementing the transitions between each scalar
-/// transience and a 1-, 2-, or 3-tuple of compatible transiences.
+/// transience and a tuple (of length 1 to 8) of compatible transiences.
 /// This is necessary because blanket impls would overlap.
 macro_rules! impl_scalar_to_tuples {
     ( $($typ:ty),* ) => {
         $(
-        // scalar => 1-tuple* => scalar
-        unsafe impl<'a, R> CanTranscendTo<(R,)> for $typ
-            where $typ: CanTranscendTo<R> {}
-        unsafe impl<'a, R> CanRecoverFrom<(R,)> for $typ
-            where $typ: CanRecoverFrom<R> {}
-
-        // scalar => 2-tuple* => scalar
-        unsafe impl<'a, R1, R2> CanTranscendTo<(R1, R2,)> for $typ
-            where $typ: CanTranscendTo<R1> + CanTranscendTo<R2> {}
-        unsafe impl<'a, R1, R2> CanRecoverFrom<(R1, R2,)> for $typ
-            where $typ: CanRecoverFrom<R1> + CanRecoverFrom<R2> {}
-
-        // scalar => 3-tuple* => scalar
-        unsafe impl<'a, R1, R2, R3> CanTranscendTo<(R1, R2, R3,)> for $typ
-            where $typ: CanTranscendTo<R1> + CanTranscendTo<R2> + CanTranscendTo<R3> {}
-        unsafe impl<'a, R1, R2, R3> CanRecoverFrom<(R1, R2, R3,)> for $typ
-            where $typ: CanRecoverFrom<R1> + CanRecoverFrom<R2> + CanRecoverFrom<R3> {}
-
-        // scalar => 4-tuple* => scalar
-        unsafe impl<'a, R1, R2, R3, R4> CanTranscendTo<(R1, R2, R3, R4)> for $typ
-            where $typ: CanTranscendTo<R1> + CanTranscendTo<R2>
-                      + CanTranscendTo<R3> + CanTranscendTo<R4> {}
-        unsafe impl<'a, R1, R2, R3, R4> CanRecoverFrom<(R1, R2, R3, R4)> for $typ
-            where $typ: CanRecoverFrom<R1> + CanRecoverFrom<R2>
-                      + CanRecoverFrom<R3> + CanRecoverFrom<R4> {}
-
-        // ------------------------------------------
-
-        // 1-tuple* => scalar => 1-tuple*
-        unsafe impl<'a, R,> CanTranscendTo<$typ> for (R,)
-            where R: CanTranscendTo<$typ> {}
-        unsafe impl<'a, R,> CanRecoverFrom<$typ> for (R,)
-            where R: CanRecoverFrom<$typ> {}
-
-        // 2-tuple* => scalar => 2-tuple*
-        unsafe impl<'a, R1, R2> CanTranscendTo<$typ> for (R1, R2)
-            where R1: CanTranscendTo<$typ>, R2: CanTranscendTo<$typ> {}
-        unsafe impl<'a, R1, R2> CanRecoverFrom<$typ> for (R1, R2)
-            where R1: CanRecoverFrom<$typ>, R2: CanRecoverFrom<$typ> {}
-
-        // 3-tuple* => scalar => 3-tuple*
-        unsafe impl<'a, R1, R2, R3> CanTranscendTo<$typ> for (R1, R2, R3)
-            where R1: CanTranscendTo<$typ>,
-                  R2: CanTranscendTo<$typ>,
-                  R3: CanTranscendTo<$typ>,  {}
-        unsafe impl<'a, R1, R2, R3> CanRecoverFrom<$typ> for (R1, R2, R3)
-            where R1: CanRecoverFrom<$typ>,
-                  R2: CanRecoverFrom<$typ>,
-                  R3: CanRecoverFrom<$typ>,  {}
-
-        // 4-tuple* => scalar => 4-tuple*
-        unsafe impl<'a, R1, R2, R3, R4> CanTranscendTo<$typ> for (R1, R2, R3, R4)
-            where R1: CanTranscendTo<$typ>,
-                  R2: CanTranscendTo<$typ>,
-                  R3: CanTranscendTo<$typ>,
-                  R4: CanTranscendTo<$typ>,  {}
-        unsafe impl<'a, R1, R2, R3, R4> CanRecoverFrom<$typ> for (R1, R2, R3, R4)
-            where R1: CanRecoverFrom<$typ>,
-                  R2: CanRecoverFrom<$typ>,
-                  R3: CanRecoverFrom<$typ>,
-                  R4: CanRecoverFrom<$typ>,  {}
+        impl_scalar_to_tuples! {
+            @tuples $typ;
+            (R1);
+            (R1, R2);
+            (R1, R2, R3);
+            (R1, R2, R3, R4);
+            (R1, R2, R3, R4, R5);
+            (R1, R2, R3, R4, R5, R6);
+            (R1, R2, R3, R4, R5, R6, R7);
+            (R1, R2, R3, R4, R5, R6, R7, R8);
+        }
+        )*
+    };
+    ( @tuples $typ:ty; $( ($($r:ident),+) );* $(;)? ) => {
+        $(
+        // scalar => n-tuple* => scalar
+        unsafe impl<'a, $($r),+> CanTranscendTo<($($r,)+)> for $typ
+            where $( $typ: CanTranscendTo<$r> ),+ {}
+        unsafe impl<'a, $($r),+> CanRecoverFrom<($($r,)+)> for $typ
+            where $( $typ: CanRecoverFrom<$r> ),+ {}
 
+        // n-tuple* => scalar => n-tuple*
+        unsafe impl<'a, $($r),+> CanTranscendTo<$typ> for ($($r,)+)
+            where $( $r: CanTranscendTo<$typ> ),+ {}
+        unsafe impl<'a, $($r),+> CanRecoverFrom<$typ> for ($($r,)+)
+            where $( $r: CanRecoverFrom<$typ> ),+ {}
         )*
-    }
+    };
 }
 impl_scalar_to_tuples! {
     Co<'a>, Contra<'a>, Inv<'a>
 }
 
+/// implements the transitions from tuples of timeless transiences (such as the
+/// transience of `(usize, String)`) to `Timeless`, and back
+macro_rules! impl_timeless_tuples {
+    ( $( ($($r:ident),+) );* $(;)? ) => {
+        $(
+        unsafe impl<$($r),+> CanTranscendTo<Timeless> for ($($r,)+)
+            where $( $r: CanTranscendTo<Timeless> ),+ {}
+        unsafe impl<$($r),+> CanRecoverFrom<Timeless> for ($($r,)+)
+            where $( $r: CanRecoverFrom<Timeless> ),+ {}
+        )*
+    };
+}
+impl_timeless_tuples! {
+    (R1);
+    (R1, R2);
+    (R1, R2, R3);
+    (R1, R2, R3, R4);
+    (R1, R2, R3, R4, R5);
+    (R1, R2, R3, R4, R5, R6);
+    (R1, R2, R3, R4, R5, R6, R7);
+    (R1, R2, R3, R4, R5, R6, R7, R8);
+}
+
 /// implements transitions between equal-length tuples where each sub-transition is
 /// also implemented (e.g., `(Co<'long>, Co<'short>)` -> `(Co<'short>, Inv<'short>)`)
 macro_rules! impl_equal_tuples {
@@ -418,4 +528,111 @@ impl_equal_tuples! {
     (A1, B1,) => (A2, B2,);
     (A1, B1, C1,) => (A2, B2, C2,);
     (A1, B1, C1, D1,) => (A2, B2, C2, D2,);
+    (A1, B1, C1, D1, E1,) => (A2, B2, C2, D2, E2,);
+    (A1, B1, C1, D1, E1, F1,) => (A2, B2, C2, D2, E2, F2,);
+    (A1, B1, C1, D1, E1, F1, G1,) => (A2, B2, C2, D2, E2, F2, G2,);
+    (A1, B1, C1, D1, E1, F1, G1, H1,) => (A2, B2, C2, D2, E2, F2, G2, H2,);
+}
+
+/// Type-level operation combining two transiences for the same lifetime(s)
+/// into the most permissive transience that both can transcend to, which is
+/// their _meet_ in the variance lattice.
+///
+/// For the single-lifetime markers, this follows the same rules the compiler
+/// uses when a lifetime appears in several positions of a type:
+///
+/// | `Meet`       | `Co<'a>`     | `Contra<'a>` | `Inv<'a>` |
+/// | :-           | :-           | :-           | :-        |
+/// | `Co<'a>`     | `Co<'a>`     | `Inv<'a>`    | `Inv<'a>` |
+/// | `Contra<'a>` | `Inv<'a>`    | `Contra<'a>` | `Inv<'a>` |
+/// | `Inv<'a>`    | `Inv<'a>`    | `Inv<'a>`    | `Inv<'a>` |
+///
+/// Meeting with [`Timeless`] leaves the other transience unchanged, and tuples
+/// of equal length are met component-wise.
+///
+/// # Examples
+/// ```
+/// use transient::transience::{Co, Contra, Inv, Meet};
+///
+/// trait Same<T> {}
+/// impl<T> Same<T> for T {}
+/// fn same<A: Same<B>, B>() {}
+///
+/// fn check<'a>() {
+///     same::<<Co<'a> as Meet<Contra<'a>>>::Output, Inv<'a>>();
+///     same::<<(Co<'a>, Co<'a>) as Meet<(Co<'a>, ())>>::Output, (Co<'a>, Co<'a>)>();
+/// }
+/// ```
+pub trait Meet<Other: Transience>: Transience {
+    /// The most permissive transience that both `Self` and `Other` can
+    /// transcend to.
+    type Output: Transience;
+}
+
+impl<R: Transience> Meet<R> for Timeless {
+    type Output = R;
+}
+
+/// implements `Meet` between the single-lifetime markers
+macro_rules! impl_meet {
+    ( $( $lhs:ident & $rhs:ident => $out:ident );* $(;)? ) => {
+        $(
+        impl<'a> Meet<$rhs<'a>> for $lhs<'a> {
+            type Output = $out<'a>;
+        }
+        )*
+    };
+}
+impl_meet! {
+    Co & Co => Co;
+    Co & Contra => Inv;
+    Co & Inv => Inv;
+    Contra & Co => Inv;
+    Contra & Contra => Contra;
+    Contra & Inv => Inv;
+    Inv & Co => Inv;
+    Inv & Contra => Inv;
+    Inv & Inv => Inv;
+}
+
+impl<'a> Meet<Timeless> for Co<'a> {
+    type Output = Co<'a>;
+}
+impl<'a> Meet<Timeless> for Contra<'a> {
+    type Output = Contra<'a>;
+}
+impl<'a> Meet<Timeless> for Inv<'a> {
+    type Output = Inv<'a>;
+}
+
+/// implements `Meet` component-wise between equal-length tuples, and between
+/// tuples and `Timeless`
+macro_rules! impl_meet_tuples {
+    { $( ($($lhs:ident,)*) & ($($rhs:ident,)*) );* $(;)? } => {
+        $(
+        impl<$($lhs),*, $($rhs),*> Meet<($($rhs),*,)> for ($($lhs),*,)
+        where
+            $( $lhs: Meet<$rhs> ),* ,
+            $( $rhs: Transience ),* ,
+        {
+            type Output = ($(<$lhs as Meet<$rhs>>::Output),*,);
+        }
+        impl<$($lhs),*> Meet<Timeless> for ($($lhs),*,)
+        where
+            $( $lhs: Transience ),*
+        {
+            type Output = Self;
+        }
+        )*
+    }
+}
+impl_meet_tuples! {
+    (A1,) & (A2,);
+    (A1, B1,) & (A2, B2,);
+    (A1, B1, C1,) & (A2, B2, C2,);
+    (A1, B1, C1, D1,) & (A2, B2, C2, D2,);
+    (A1, B1, C1, D1, E1,) & (A2, B2, C2, D2, E2,);
+    (A1, B1, C1, D1, E1, F1,) & (A2, B2, C2, D2, E2, F2,);
+    (A1, B1, C1, D1, E1, F1, G1,) & (A2, B2, C2, D2, E2, F2, G2,);
+    (A1, B1, C1, D1, E1, F1, G1, H1,) & (A2, B2, C2, D2, E2, F2, G2, H2,);
 }
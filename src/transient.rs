@@ -1,7 +1,9 @@
 //! Defines the [`Transient`] trait capturing the temporal information necessary
 //! to safely erase and restore non-`'static` concrete types.
 use crate::any::{Any, TypeId};
-use crate::transience::Transience;
+use crate::transience::{CanTranscendTo, Transience};
+#[cfg(feature = "alloc")]
+use alloc::boxed::Box;
 
 /// Unsafe trait defining the lifetime-relationships of a potentially non-`'static`
 /// type so that it can be safely erased to [`dyn Any`][crate::Any]. This trait can
@@ -83,7 +85,7 @@ use crate::transience::Transience;
 /// Note that this pattern of composing tuples should in theory hold
 /// for _any_ number of lifetimes (i.e. a type with 100 lifetimes using
 /// a 100-tuple of variances), but in practice the `Transience` trait
-/// is only actually implemented for 1-, 2-, 3-, and 4-tuples. If you
+/// is only actually implemented for tuples of up to 8 elements. If you
 /// need more than this feel free to submit an issue requesting it.
 ///
 /// # Examples
@@ -129,8 +131,7 @@ use crate::transience::Transience;
 /// ## Types with multiple lifetime parameters
 ///
 /// Now consider a struct that borrows 2 string slices with independent
-/// lifetime parameters (which is currently not supported by the derive
-/// macro):
+/// lifetime parameters:
 /// ```
 /// struct TwoRefs<'a, 'b> {
 ///     a: &'a str,
@@ -150,6 +151,8 @@ use crate::transience::Transience;
 ///     type Transience = (Inv<'a>, Inv<'b>);
 /// }
 /// ```
+/// The derive macro follows this same pattern for types with multiple lifetimes,
+/// using one tuple element per lifetime in the order they are declared.
 ///
 /// Another option is to establish a relationship between the lifetimes that allows
 /// a most conservative `Transience` to be unambiguously identified for use in the impl:
@@ -218,6 +221,45 @@ use crate::transience::Transience;
 /// `Transient`, or request that the impl be added by this crate or the type's
 /// crate.
 ///
+/// ## Trait objects
+/// References to (and boxes of) trait objects such as `&'a (dyn Display + 'a)`
+/// can also implement `Transient` by replacing both the lifetime of the pointer
+/// and that of the trait object with `'static` in the `Static` type. Since the
+/// trait object's lifetime is covariant behind a shared reference or `Box` but
+/// invariant behind a mutable reference, these should use `Co<'a>` and `Inv<'a>`
+/// respectively. This crate provides such impls for the erased `Debug`, `Display`,
+/// and `Error` traits (along with their `+ Send` and `+ Send + Sync` variants),
+/// and they can be implemented for a custom trait in the same way:
+/// ```
+/// use transient::{Any, Co, Downcast, Transient};
+///
+/// trait Shape {
+///     fn area(&self) -> f64;
+/// }
+///
+/// unsafe impl<'a> Transient for &'a (dyn Shape + 'a) {
+///     type Static = &'static (dyn Shape + 'static);
+///     type Transience = Co<'a>;
+/// }
+///
+/// struct Square<'a>(&'a f64);
+/// impl Shape for Square<'_> {
+///     fn area(&self) -> f64 {
+///         self.0 * self.0
+///     }
+/// }
+///
+/// let side = 2.0;
+/// let square = Square(&side);
+/// let shape: &dyn Shape = &square;
+/// let erased: &dyn Any<Co> = &shape;
+/// assert_eq!(erased.downcast_ref::<&dyn Shape>().unwrap().area(), 4.0);
+/// ```
+/// Note that the `TypeId` of such a type identifies the _trait object_ rather
+/// than the concrete type behind it, so every `&dyn Shape` erases to the same
+/// type regardless of the value it points to; in the example above, the erased
+/// value could not be restored as a `&Square`.
+///
 /// # Safety
 /// - The [`Static`][Self::Static] associated type must be the same type as the
 ///   implementing type, but with all lifetime parameters replaced by `'static` and
@@ -236,8 +278,12 @@ use crate::transience::Transience;
 ///   safe default with `Co` and `Contra` optionally substituted where appropriate.
 ///   Choosing `Co` or `Contra` for any lifetime parameter without respecting the
 ///   rules of [Subtyping and Variance], or excluding any independent lifetime
-///   parameter from the `Transience` is undefined behavior.
+///   parameter from the `Transience` is undefined behavior. The
+///   [`assert_covariant!`] and [`assert_contravariant!`] macros can be used to
+///   have the compiler verify a choice of `Co` or `Contra`, respectively.
 ///
+/// [`assert_covariant!`]: crate::assert_covariant
+/// [`assert_contravariant!`]: crate::assert_contravariant
 /// [`dyn Any`]: https://doc.rust-lang.org/std/any/index.html#any-and-typeid
 /// [`Timeless`]: crate::transience::Timeless
 /// [`Inv<'a>`]: crate::transience::Inv
@@ -299,6 +345,7 @@ pub unsafe trait Transient: Sized {
     /// `Transience` type (such as [`Co`][crate::Co]) and explicitly specify
     /// `dyn Any<Co>` even for trivial usages (although using `dyn Any<_>` and
     /// letting type-inference fill-in-the-blank will also work in some cases).
+    #[cfg(feature = "alloc")]
     #[inline]
     fn erase<'a>(self: Box<Self>) -> Box<dyn Any<Self::Transience> + 'a>
     where
@@ -329,12 +376,69 @@ pub unsafe trait Transient: Sized {
         let () = Self::CHECK;
         self
     }
+
+    /// Cast `Box<Self>` to `Box<dyn Any<R>>` for an explicitly chosen
+    /// transience `R`, which must be reachable from the `Transience` of the
+    /// `Transient` implementation (e.g. `Co<'a>` to `Inv<'a>`).
+    ///
+    /// This performs the same conversion as an unsizing coercion, but makes
+    /// the variance downgrade explicit and reports an illegal transition
+    /// (such as `Inv<'a>` to `Co<'a>`) as an unsatisfied `CanTranscendTo`
+    /// bound at the call site. Note that there is no equivalent for values
+    /// that have already been erased, since the `dyn Any<R>` trait object is
+    /// tied to the transience it was created with.
+    ///
+    /// # Examples
+    /// ```
+    /// use transient::{Any, Downcast, Inv, Transient};
+    ///
+    /// let string = "qwer".to_string();
+    /// let erased: Box<dyn Any<Inv>> = Box::new(string.as_str()).transcend::<Inv>();
+    /// assert_eq!(*erased.downcast::<&str>().unwrap(), "qwer");
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn transcend<'a, R: Transience>(self: Box<Self>) -> Box<dyn Any<R> + 'a>
+    where
+        Self: 'a,
+        Self::Transience: CanTranscendTo<R>,
+    {
+        let () = Self::CHECK;
+        self
+    }
+
+    /// Cast `&Self` to `&dyn Any<R>` for an explicitly chosen transience `R`.
+    ///
+    /// See [`transcend`][Self::transcend] for details.
+    #[inline]
+    fn transcend_ref<'a, R: Transience>(&self) -> &(dyn Any<R> + 'a)
+    where
+        Self: 'a,
+        Self::Transience: CanTranscendTo<R>,
+    {
+        let () = Self::CHECK;
+        self
+    }
+
+    /// Cast `&mut Self` to `&mut dyn Any<R>` for an explicitly chosen
+    /// transience `R`.
+    ///
+    /// See [`transcend`][Self::transcend] for details.
+    #[inline]
+    fn transcend_mut<'a, R: Transience>(&mut self) -> &mut (dyn Any<R> + 'a)
+    where
+        Self: 'a,
+        Self::Transience: CanTranscendTo<R>,
+    {
+        let () = Self::CHECK;
+        self
+    }
 }
 
 #[track_caller]
 const fn check_static_type<T: Transient>() {
     assert!(
-        std::mem::size_of::<T>() == std::mem::size_of::<T::Static>(),
+        core::mem::size_of::<T>() == core::mem::size_of::<T::Static>(),
         "Size mismatch! `T::Static` should be the same as `T` \
           but with its lifetimes replaced by `'static`"
     );
@@ -355,17 +459,51 @@ unsafe impl<S: Static> Transient for S {
 
 mod std_impls {
     use super::{Static, Transient};
-    use crate::{Co, Inv};
-
-    use std::any::Any as StdAny;
-    use std::borrow::{Cow, ToOwned};
-    use std::collections::HashMap;
-
+    use crate::{Co, Contra, Inv, Invariant};
+
+    #[cfg(feature = "alloc")]
+    use alloc::{
+        borrow::{Cow, ToOwned},
+        boxed::Box,
+        string::String,
+        vec::Vec,
+    };
+    use core::any::Any as StdAny;
+    use core::convert::Infallible;
+    use core::fmt::{Debug, Display};
+    use core::future::Future;
+    use core::pin::Pin;
+    #[cfg(feature = "std")]
+    use std::{collections::HashMap, error::Error};
+
+    /// Implements `Transient` for shared and mutable references (up to two
+    /// levels deep) to the given type. The optional transiences are appended
+    /// to those of the references themselves, and a separate list can be given
+    /// after `mut` for the cases where the type is behind a mutable reference
+    /// (and thus invariant), along with an optional `where` clause restricting
+    /// those cases (e.g. to element types whose transience is already
+    /// [`Invariant`]).
     macro_rules! impl_refs {
         {
-            $type_:ty
+            $type_:ty [$($params:tt)*] ($($trans:ty),*) mut ($($mut_trans:ty),*)
+            $(where $($mut_where:tt)+)?
+        }
+        =>
+        {
+            impl_refs!(@impl $type_ [$($params)*] [$($trans),*] [$($mut_trans),*]
+                [$($($mut_where)+)?]);
+        };
+        {
+            $type_:ty [$($params:tt)*] $( ($($trans:ty),+) )?
+        }
+        =>
+        {
+            impl_refs!(@impl $type_ [$($params)*] [$($($trans),+)?] [$($($trans),+)?] []);
+        };
+        {
+            @impl $type_:ty
             [$($param:tt $(: $bound1:tt $(+ $bounds:tt)*)?),*]
-            $( ($($trans:ty),+) )?
+            [$($trans:ty),*] [$($mut_trans:ty),*] [$($mut_where:tt)*]
         }
         =>
         {
@@ -373,39 +511,51 @@ mod std_impls {
             unsafe impl<'_a, $( $param $( : $bound1 $(+ $bounds )* )? ),*>
             Transient for &'_a $type_ {
                 type Static = &'static <$type_ as Transient>::Static;
-                type Transience = (Co<'_a> $($(, $trans)+)?);
+                type Transience = (Co<'_a> $(, $trans)*);
             }
 
             #[allow(unused_parens)]
             unsafe impl<'_a, $( $param $( : $bound1 $(+ $bounds )* )? ),*>
-            Transient for &'_a mut $type_ {
+            Transient for &'_a mut $type_
+            where
+                $($mut_where)*
+            {
                 type Static = &'static mut <$type_ as Transient>::Static;
-                type Transience = (Co<'_a> $($(, $trans)+)?);
+                type Transience = (Co<'_a> $(, $mut_trans)*);
             }
 
             unsafe impl<'_a, '_b, $( $param $( : $bound1 $(+ $bounds )* )? ),*>
             Transient for &'_a &'_b $type_ {
                 type Static = &'static &'static <$type_ as Transient>::Static;
-                type Transience = (Co<'_a>, Co<'_b> $($(, $trans)+)?);
+                type Transience = (Co<'_a>, Co<'_b> $(, $trans)*);
             }
 
             unsafe impl<'_a, '_b, $( $param $( : $bound1 $(+ $bounds )* )? ),*>
-            Transient for &'_a mut &'_b $type_ {
+            Transient for &'_a mut &'_b $type_
+            where
+                $($mut_where)*
+            {
                 type Static = &'static mut &'static <$type_ as Transient>::Static;
-                type Transience = (Co<'_a>, Inv<'_b> $($(, $trans)+)?);
+                type Transience = (Co<'_a>, Inv<'_b> $(, $mut_trans)*);
             }
 
             unsafe impl<'_a, '_b, $( $param $( : $bound1 $(+ $bounds )* )? ),*>
-            Transient for &'_a &'_b mut $type_ {
+            Transient for &'_a &'_b mut $type_
+            where
+                $($mut_where)*
+            {
                 type Static = &'static &'static mut <$type_ as Transient>::Static;
-                type Transience = (Co<'_a>, Co<'_b> $($(, $trans)+)?);
+                type Transience = (Co<'_a>, Co<'_b> $(, $mut_trans)*);
             }
             unsafe impl<'_a, '_b, $( $param $( : $bound1 $(+ $bounds )* )? ),*>
-            Transient for &'_a mut &'_b mut $type_ {
-                type Static = &'static mut &'static <$type_ as Transient>::Static;
-                type Transience = (Co<'_a>, Inv<'_b> $($(, $trans)+)?);
+            Transient for &'_a mut &'_b mut $type_
+            where
+                $($mut_where)*
+            {
+                type Static = &'static mut &'static mut <$type_ as Transient>::Static;
+                type Transience = (Co<'_a>, Inv<'_b> $(, $mut_trans)*);
             }
-        }
+        };
     }
 
     macro_rules! impl_primatives {
@@ -420,37 +570,64 @@ mod std_impls {
     impl_primatives! {
         isize, i8, i16, i32, i64, i128,
         usize, u8, u16, u32, u64, u128,
-        f32, f64, String, Box<str>, ()
+        f32, f64, bool, char, (), Infallible
     }
+    #[cfg(feature = "alloc")]
+    impl_primatives! { String, Box<str> }
 
     unsafe impl<'a> Transient for &'a str {
         type Static = &'static str;
         type Transience = Co<'a>;
     }
-    impl_refs!(&'a str ['a]);
+    impl_refs!(&'a str ['a] (Co<'a>) mut (Inv<'a>));
 
     unsafe impl<'a, T: Transient> Transient for &'a [T] {
         type Static = &'static [T::Static];
-        type Transience = Co<'a>;
+        type Transience = (Co<'a>, T::Transience);
+    }
+    impl_refs! {
+        &'a [T] ['a, T: Transient] (Co<'a>, T::Transience) mut (Inv<'a>, T::Transience)
+        where T::Transience: Invariant
     }
-    impl_refs!(&'a [T] ['a, T: Transient]);
 
+    unsafe impl<T: Transient, const N: usize> Transient for [T; N] {
+        type Static = [T::Static; N];
+        type Transience = T::Transience;
+    }
+    unsafe impl<'a, T: Transient, const N: usize> Transient for &'a [T; N] {
+        type Static = &'static [T::Static; N];
+        type Transience = (Co<'a>, T::Transience);
+    }
+
+    #[cfg(feature = "alloc")]
     unsafe impl<T: Transient> Transient for Vec<T> {
         type Static = Vec<T::Static>;
         type Transience = T::Transience;
     }
-    impl_refs! { Vec<T> [T: Transient] (T::Transience) }
+    #[cfg(feature = "alloc")]
+    impl_refs! {
+        Vec<T> [T: Transient] (T::Transience) mut (T::Transience)
+        where T::Transience: Invariant
+    }
 
+    #[cfg(feature = "std")]
     unsafe impl<K: Transient, V: Transient> Transient for HashMap<K, V> {
         type Static = HashMap<K::Static, V::Static>;
         type Transience = (K::Transience, V::Transience);
     }
-    impl_refs!(HashMap<K, V> [K: Transient, V: Transient] (K::Transience, V::Transience));
+    #[cfg(feature = "std")]
+    impl_refs! {
+        HashMap<K, V> [K: Transient, V: Transient]
+        (K::Transience, V::Transience) mut (K::Transience, V::Transience)
+        where K::Transience: Invariant, V::Transience: Invariant
+    }
 
+    #[cfg(feature = "alloc")]
     unsafe impl<T: Transient> Transient for Box<[T]> {
         type Static = Box<[T::Static]>;
         type Transience = T::Transience;
     }
+    #[cfg(feature = "alloc")]
     unsafe impl<'a, T: Transient + ToOwned> Transient for Cow<'a, T>
     where
         T::Static: ToOwned,
@@ -458,6 +635,19 @@ mod std_impls {
         type Static = Cow<'static, T::Static>;
         type Transience = (Co<'a>, T::Transience);
     }
+    #[cfg(feature = "alloc")]
+    unsafe impl<'a> Transient for Cow<'a, str> {
+        type Static = Cow<'static, str>;
+        type Transience = Co<'a>;
+    }
+    #[cfg(feature = "alloc")]
+    unsafe impl<'a, T: Transient + Clone> Transient for Cow<'a, [T]>
+    where
+        T::Static: Clone,
+    {
+        type Static = Cow<'static, [T::Static]>;
+        type Transience = (Co<'a>, T::Transience);
+    }
     unsafe impl<T: Transient> Transient for Option<T> {
         type Static = Option<T::Static>;
         type Transience = T::Transience;
@@ -467,6 +657,134 @@ mod std_impls {
         type Transience = T::Transience;
     }
 
+    /// Function pointers are _contravariant_ in the lifetimes of their arguments
+    /// and _covariant_ in the lifetimes of their return type. These impls only
+    /// apply when the lifetime is named in the type (e.g. `fn(&'a str)`); the
+    /// higher-ranked `fn(&str)` is a distinct `'static` type which is valid for
+    /// any lifetime, and therefore gets a `Static` impl instead.
+    ///
+    /// The supported shapes are a reference (`&'a T` or `&'a mut T`) as the first
+    /// argument followed by up to 3 `'static` arguments and a `'static` return
+    /// type, such as `fn(&'a str, usize) -> bool`, and `fn() -> &'a T`. A lifetime
+    /// in any other position (e.g. `fn(usize, &'a str)`), or in more than one
+    /// place (e.g. `fn(&'a str) -> &'a str`), is not supported, since an impl for
+    /// each would overlap with the impls above.
+    macro_rules! impl_fn_pointers {
+        ( $( ($($arg:ident),*) );* $(;)? ) => {
+            $(
+            unsafe impl<'a, T: ?Sized + 'static, $($arg: 'static,)* R: 'static> Transient
+                for fn(&'a T, $($arg),*) -> R
+            {
+                type Static = fn(&'static T, $($arg),*) -> R;
+                type Transience = Contra<'a>;
+            }
+            unsafe impl<'a, T: ?Sized + 'static, $($arg: 'static,)* R: 'static> Transient
+                for fn(&'a mut T, $($arg),*) -> R
+            {
+                type Static = fn(&'static mut T, $($arg),*) -> R;
+                type Transience = Contra<'a>;
+            }
+            impl<T: ?Sized + 'static, $($arg: 'static,)* R: 'static> Static
+                for fn(&T, $($arg),*) -> R {}
+            impl<T: ?Sized + 'static, $($arg: 'static,)* R: 'static> Static
+                for fn(&mut T, $($arg),*) -> R {}
+            )*
+        };
+    }
+    impl_fn_pointers! {
+        ();
+        (A1);
+        (A1, A2);
+        (A1, A2, A3);
+    }
+    unsafe impl<'a, T: ?Sized + 'static> Transient for fn() -> &'a T {
+        type Static = fn() -> &'static T;
+        type Transience = Co<'a>;
+    }
+
+    /// Implements `Transient` for tuples of `Transient` types, where the
+    /// `Transience` is the tuple of the elements' transiences. When several
+    /// elements share a lifetime with different variances, the combined tuple
+    /// can only transcend to the transiences that all of them allow (e.g. a
+    /// `(Co<'a>, Contra<'a>)` tuple can be erased to `dyn Any<Inv<'a>>` but
+    /// not to `dyn Any<Co<'a>>`).
+    macro_rules! impl_tuples {
+        ( $( ($($ty:ident),+) );* $(;)? ) => {
+            $(
+            unsafe impl<$($ty: Transient),+> Transient for ($($ty,)+) {
+                type Static = ($($ty::Static,)+);
+                type Transience = ($($ty::Transience,)+);
+            }
+            )*
+        }
+    }
+
+    impl_tuples! {
+        (A);
+        (A, B);
+        (A, B, C);
+        (A, B, C, D);
+        (A, B, C, D, E);
+        (A, B, C, D, E, F);
+        (A, B, C, D, E, F, G);
+        (A, B, C, D, E, F, G, H);
+    }
+
+    /// Implements `Transient` for shared and mutable references to, and boxes
+    /// of, the given trait objects. The lifetime of a trait object is covariant
+    /// behind a shared reference or `Box`, but invariant behind a mutable one.
+    macro_rules! impl_trait_objects {
+        ( $( $trait_:ident $(+ $auto:ident)* ),* $(,)? ) => {
+            $(
+            unsafe impl<'a> Transient for &'a (dyn $trait_ $(+ $auto)* + 'a) {
+                type Static = &'static (dyn $trait_ $(+ $auto)* + 'static);
+                type Transience = Co<'a>;
+            }
+            unsafe impl<'a> Transient for &'a mut (dyn $trait_ $(+ $auto)* + 'a) {
+                type Static = &'static mut (dyn $trait_ $(+ $auto)* + 'static);
+                type Transience = Inv<'a>;
+            }
+            #[cfg(feature = "alloc")]
+            unsafe impl<'a> Transient for Box<dyn $trait_ $(+ $auto)* + 'a> {
+                type Static = Box<dyn $trait_ $(+ $auto)* + 'static>;
+                type Transience = Co<'a>;
+            }
+            )*
+        }
+    }
+
+    impl_trait_objects! {
+        Debug, Debug + Send, Debug + Send + Sync,
+        Display, Display + Send, Display + Send + Sync,
+    }
+    #[cfg(feature = "std")]
+    impl_trait_objects! { Error, Error + Send, Error + Send + Sync }
+
+    /// Implements `Transient` for pinned mutable references to, and pinned
+    /// boxes of, the given future trait objects (e.g. the state of an `async`
+    /// block borrowing local data). As with other trait objects, the lifetime
+    /// is invariant behind the mutable reference but covariant in the `Box`.
+    macro_rules! impl_futures {
+        ( $( Future $(+ $auto:ident)* ),* $(,)? ) => {
+            $(
+            unsafe impl<'a, T: 'static> Transient
+            for Pin<&'a mut (dyn Future<Output = T> $(+ $auto)* + 'a)> {
+                type Static = Pin<&'static mut (dyn Future<Output = T> $(+ $auto)* + 'static)>;
+                type Transience = Inv<'a>;
+            }
+            #[cfg(feature = "alloc")]
+            unsafe impl<'a, T: 'static> Transient
+            for Pin<Box<dyn Future<Output = T> $(+ $auto)* + 'a>> {
+                type Static = Pin<Box<dyn Future<Output = T> $(+ $auto)* + 'static>>;
+                type Transience = Co<'a>;
+            }
+            )*
+        }
+    }
+
+    impl_futures! { Future, Future + Send }
+
+    #[cfg(feature = "alloc")]
     impl Static for Box<dyn StdAny> {}
 
     unsafe impl<'a> Transient for &'a dyn StdAny {
@@ -0,0 +1,10 @@
+//! Ensures that `assert_covariant!` rejects a type that is invariant
+use transient::assert_covariant;
+
+struct MutRef<'a>(&'a mut &'a str);
+
+assert_covariant!(for<'a> MutRef<'a>);
+
+fn main() {
+    // this test should fail to compile
+}
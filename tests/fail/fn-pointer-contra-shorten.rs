@@ -0,0 +1,11 @@
+//! Ensures that a function pointer requiring a long-lived argument cannot be
+//! erased as accepting a shorter-lived one
+use transient::*;
+
+fn shorten<'short>(func: fn(&'static str) -> usize) -> Box<dyn Any<Contra<'short>>> {
+    Box::new(func)
+}
+
+fn main() {
+    // this test should fail to compile
+}
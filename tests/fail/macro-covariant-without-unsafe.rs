@@ -0,0 +1,12 @@
+//! Ensures that the `transient!` macro requires the `unsafe_` prefix
+use transient::*;
+
+struct S<'a>(&'a str);
+
+transient! {
+    S<'a = co>;
+}
+
+fn main() {
+    // this test should fail to compile
+}
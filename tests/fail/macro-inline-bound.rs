@@ -0,0 +1,12 @@
+//! Ensures that the `transient!` macro points inline bounds to the `where` clause
+use transient::*;
+
+struct Wrapper<'a, T>(&'a T);
+
+transient! {
+    Wrapper<'a = unsafe_co, T: Clone>;
+}
+
+fn main() {
+    // this test should fail to compile
+}
@@ -0,0 +1,22 @@
+//! Ensures that a contravariant `&mut dyn Any<Contra<'long>>` cannot be mutably
+//! downcast, since the restored `&mut Callback<'long>` could be used to write a
+//! callback that requires `'long` into a slot that is called with `'short`
+use transient::*;
+
+struct Callback<'a>(fn(&'a str));
+unsafe impl<'a> Transient for Callback<'a> {
+    type Static = Callback<'static>;
+    type Transience = Contra<'a>;
+}
+
+fn overwrite<'short, 'long: 'short>(
+    slot: &'short mut Callback<'short>,
+    value: Callback<'long>,
+) {
+    let erased: &mut dyn Any<Contra<'long>> = slot;
+    *erased.downcast_mut::<Callback<'long>>().unwrap() = value;
+}
+
+fn main() {
+    // this test should fail to compile
+}
@@ -0,0 +1,18 @@
+//! Ensures that a covariant `&mut dyn Any<Co<'short>>` cannot be mutably
+//! downcast, since the restored `&mut &'short str` could be used to write a
+//! short-lived reference into a value that was originally a `&'long str`
+use transient::*;
+
+fn overwrite<'short, 'long: 'short>(slot: &'short mut &'long str, value: &'short str) {
+    let erased: &mut dyn Any<Co<'short>> = slot;
+    *erased.downcast_mut::<&'short str>().unwrap() = value;
+}
+
+fn main() {
+    let mut slot: &'static str = "static";
+    {
+        let short = "short".to_string();
+        overwrite(&mut slot, &short);
+    }
+    println!("{slot}");
+}
@@ -0,0 +1,13 @@
+//! Ensures that a trait object is only mutably downcast if _every_ component
+//! of its transience is invariant
+use transient::*;
+
+fn restore<'a, 'b>(
+    erased: &'a mut dyn Any<(Inv<'a>, Co<'b>)>,
+) -> &'a mut (&'a mut usize, &'b str) {
+    erased.downcast_mut().unwrap()
+}
+
+fn main() {
+    // this test should fail to compile
+}
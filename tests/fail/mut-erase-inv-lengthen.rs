@@ -0,0 +1,22 @@
+//! Ensures that a `&mut Callback<'short>` cannot be erased as `&mut dyn Any<Inv<'long>>`,
+//! since the `Inv` transience must match the lifetime of the referent exactly
+//! in order for the mutable downcast to be sound
+use transient::*;
+
+struct Callback<'a>(fn(&'a str));
+unsafe impl<'a> Transient for Callback<'a> {
+    type Static = Callback<'static>;
+    type Transience = Contra<'a>;
+}
+
+fn overwrite<'short, 'long: 'short>(
+    slot: &'short mut Callback<'short>,
+    value: Callback<'long>,
+) {
+    let erased: &mut dyn Any<Inv<'long>> = slot;
+    *erased.downcast_mut::<Callback<'long>>().unwrap() = value;
+}
+
+fn main() {
+    // this test should fail to compile
+}
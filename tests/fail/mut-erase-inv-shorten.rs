@@ -0,0 +1,13 @@
+//! Ensures that a `&mut &'long str` cannot be erased as `&mut dyn Any<Inv<'short>>`,
+//! since the `Inv` transience must match the lifetime of the referent exactly
+//! in order for the mutable downcast to be sound
+use transient::*;
+
+fn overwrite<'short, 'long: 'short>(slot: &'short mut &'long str, value: &'short str) {
+    let erased: &mut dyn Any<Inv<'short>> = slot;
+    *erased.downcast_mut::<&'short str>().unwrap() = value;
+}
+
+fn main() {
+    // this test should fail to compile
+}
@@ -0,0 +1,14 @@
+//! Ensures that the inner lifetime of a `&mut &str` is treated as invariant, so
+//! that a shorter-lived `&str` cannot be written through the restored value
+use transient::*;
+
+fn shorten<'a, 'long: 'a, 'short>(
+    value: &'a mut &'long str,
+) -> &'a mut &'short str {
+    let erased: Box<dyn Any<(Co<'a>, Inv<'_>)> + 'a> = Box::new(value);
+    *erased.downcast::<&mut &'short str>().unwrap()
+}
+
+fn main() {
+    // this test should fail to compile
+}
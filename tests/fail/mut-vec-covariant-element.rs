@@ -0,0 +1,13 @@
+//! Ensures that a `&mut Vec<T>` is only `Transient` when the transience of `T`
+//! is invariant, since a covariant element type could otherwise be shortened
+//! and used to push a short-lived value into the original `Vec`
+use transient::*;
+
+fn push<'short, 'long: 'short>(vec: &'short mut Vec<&'long str>, value: &'short str) {
+    let erased: Box<dyn Any<(Co<'short>, Co<'short>)> + 'short> = Box::new(vec);
+    erased.downcast::<&mut Vec<&'short str>>().unwrap().push(value);
+}
+
+fn main() {
+    // this test should fail to compile
+}
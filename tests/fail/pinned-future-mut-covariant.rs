@@ -0,0 +1,15 @@
+//! Ensures that a pinned mutable reference to a future cannot be erased as
+//! covariant, since the lifetime of the trait object is invariant behind it
+use std::future::Future;
+use std::pin::Pin;
+use transient::*;
+
+fn relax<'a>(
+    future: Pin<&'a mut (dyn Future<Output = ()> + 'a)>,
+) -> Box<dyn Any<Co<'a>> + 'a> {
+    Box::new(future)
+}
+
+fn main() {
+    // this test should fail to compile
+}
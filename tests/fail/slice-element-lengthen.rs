@@ -0,0 +1,11 @@
+//! Ensures that the transience of the elements of a slice is tracked, so that
+//! borrowed elements cannot be restored with a longer lifetime
+use transient::*;
+
+fn lengthen<'a>(erased: &'a dyn Any<Co<'a>>) -> &'a &'a [&'static str] {
+    erased.downcast_ref::<&'a [&'static str]>().unwrap()
+}
+
+fn main() {
+    // this test should fail to compile
+}
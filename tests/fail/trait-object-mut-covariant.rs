@@ -0,0 +1,12 @@
+//! Ensures that a mutable reference to a trait object cannot be erased as
+//! covariant, since the lifetime of the trait object is invariant behind it
+use std::fmt::Display;
+use transient::*;
+
+fn relax<'a>(value: &'a mut (dyn Display + 'a)) -> Box<dyn Any<Co<'a>> + 'a> {
+    Box::new(value)
+}
+
+fn main() {
+    // this test should fail to compile
+}
@@ -0,0 +1,16 @@
+//! Ensures that the explicit `transcend` methods reject illegal transitions
+use transient::*;
+
+struct MutRef<'a>(&'a mut &'a str);
+unsafe impl<'a> Transient for MutRef<'a> {
+    type Static = MutRef<'static>;
+    type Transience = Inv<'a>;
+}
+
+fn relax<'a>(value: &'a MutRef<'a>) -> &'a dyn Any<Co<'a>> {
+    value.transcend_ref::<Co<'a>>()
+}
+
+fn main() {
+    // this test should fail to compile
+}
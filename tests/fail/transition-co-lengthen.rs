@@ -0,0 +1,12 @@
+//! Ensures that a covariant transience cannot transcend to a longer lifetime
+use transient::*;
+
+fn transcends<Src: CanTranscendTo<Dst>, Dst: Transience>() {}
+
+fn lengthen<'short, 'long: 'short>() {
+    transcends::<Co<'short>, Co<'long>>();
+}
+
+fn main() {
+    // this test should fail to compile
+}
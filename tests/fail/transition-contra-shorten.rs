@@ -0,0 +1,12 @@
+//! Ensures that a contravariant transience cannot transcend to a shorter lifetime
+use transient::*;
+
+fn transcends<Src: CanTranscendTo<Dst>, Dst: Transience>() {}
+
+fn shorten<'short, 'long: 'short>() {
+    transcends::<Contra<'long>, Contra<'short>>();
+}
+
+fn main() {
+    // this test should fail to compile
+}
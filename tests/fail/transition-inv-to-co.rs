@@ -0,0 +1,13 @@
+//! Ensures that an invariant transience cannot transcend to a covariant one,
+//! even though a covariant type *can* be recovered from it
+use transient::*;
+
+fn transcends<Src: CanTranscendTo<Dst>, Dst: Transience>() {}
+
+fn relax<'a>() {
+    transcends::<Inv<'a>, Co<'a>>();
+}
+
+fn main() {
+    // this test should fail to compile
+}
@@ -0,0 +1,12 @@
+//! Ensures that a covariant type cannot be recovered with a longer lifetime
+use transient::*;
+
+fn recovers<Dst: CanRecoverFrom<Src>, Src: Transience>() {}
+
+fn lengthen<'short, 'long: 'short>() {
+    recovers::<Co<'long>, Co<'short>>();
+}
+
+fn main() {
+    // this test should fail to compile
+}
@@ -0,0 +1,21 @@
+use transient::*;
+
+struct T<'s, 'l, 'i>(fn(&'s str), &'l str, std::cell::Cell<&'i str>);
+
+unsafe impl<'s, 'l, 'i> Transient for T<'s, 'l, 'i> {
+    type Static = T<'static, 'static, 'static>;
+    type Transience = (Contra<'s>, Co<'l>, Inv<'i>);
+}
+
+// This function lengthens the first and shortens the second lifetime as
+// allowed, but also requires the *invariant* third lifetime parameter to
+// shorten from 'long to 'short, which should be *rejected*.
+fn rearrange<'b, 'short, 'long: 'short>(
+    value: &'b T<'short, 'long, 'long>,
+) -> &'b dyn Any<(Contra<'long>, Co<'short>, Inv<'short>)> {
+    value
+}
+
+fn main() {
+    // this test should fail to compile
+}
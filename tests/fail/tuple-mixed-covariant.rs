@@ -0,0 +1,17 @@
+//! Ensures that a tuple using the same lifetime both covariantly and
+//! contravariantly cannot be erased as covariant
+use transient::*;
+
+struct Callback<'a>(fn(&'a str));
+unsafe impl<'a> Transient for Callback<'a> {
+    type Static = Callback<'static>;
+    type Transience = Contra<'a>;
+}
+
+fn erase<'a>(value: (Callback<'a>, &'a str)) -> Box<dyn Any<Co<'a>> + 'a> {
+    Box::new(value)
+}
+
+fn main() {
+    // this test should fail to compile
+}
@@ -0,0 +1,13 @@
+//! Ensures that a `TransientVec` rejects values borrowing for less than `'src`
+use transient::collections::TransientVec;
+
+fn main() {
+    let outer = "outer".to_string();
+    let mut vec = TransientVec::new();
+    vec.push(outer.as_str());
+    {
+        let inner = "inner".to_string();
+        vec.push(inner.as_str());
+    }
+    assert_eq!(vec.len(), 2);
+}